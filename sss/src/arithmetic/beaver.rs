@@ -0,0 +1,89 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+use crate::polynomial::generate_shares::generate_shares;
+use crate::reconstruction::reconstruct_secret::reconstruct_secret;
+use super::share_ops::{add_constant, add_shares, scale_shares, sub_shares};
+
+/// Shares of random a, b and c = a * b, dealt ahead of time by a trusted dealer.
+pub struct BeaverTriple {
+    pub a: Vec<(BigUint, BigUint)>,
+    pub b: Vec<(BigUint, BigUint)>,
+    pub c: Vec<(BigUint, BigUint)>,
+}
+
+/// Product shares plus the two values the holders opened to get them.
+pub struct BeaverProduct {
+    pub shares: Vec<(BigUint, BigUint)>,
+    pub d: BigUint,
+    pub e: BigUint,
+}
+
+pub fn deal_triple<R: Rng + ?Sized>(n: u32, k: u32, prime: &BigUint, rng: &mut R) -> BeaverTriple {
+    let a = rng.gen_biguint_below(prime);
+    let b = rng.gen_biguint_below(prime);
+    let c = (&a * &b) % prime;
+
+    BeaverTriple {
+        a: generate_shares(&a, n, k, prime, rng),
+        b: generate_shares(&b, n, k, prime, rng),
+        c: generate_shares(&c, n, k, prime, rng),
+    }
+}
+
+/// Multiplies two shared secrets with one triple. The holders open
+/// d = x - a and e = y - b, which are masked by the random a and b, and then
+/// locally compute shares of
+///   x * y = c + d * b + e * a + d * e,
+/// which is linear in the shares, so the result stays at degree k - 1.
+pub fn beaver_multiply(
+    x: &[(BigUint, BigUint)],
+    y: &[(BigUint, BigUint)],
+    triple: &BeaverTriple,
+    k: u32,
+    prime: &BigUint,
+) -> Result<BeaverProduct, String> {
+    if x.len() < k as usize {
+        return Err(format!("need at least k = {} holders to open d and e", k));
+    }
+    let d_shares = sub_shares(x, &triple.a, prime)?;
+    let e_shares = sub_shares(y, &triple.b, prime)?;
+    let d = reconstruct_secret(&d_shares[..k as usize], prime);
+    let e = reconstruct_secret(&e_shares[..k as usize], prime);
+
+    let db = scale_shares(&triple.b, &d, prime);
+    let ea = scale_shares(&triple.a, &e, prime);
+    let product = add_shares(&add_shares(&triple.c, &db, prime)?, &ea, prime)?;
+    let shares = add_constant(&product, &((&d * &e) % prime), prime);
+    Ok(BeaverProduct { shares, d, e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beaver_multiply() {
+        let prime = BigUint::from(7919u32);
+        let mut rng = rand::thread_rng();
+        let x = generate_shares(&BigUint::from(123u32), 4, 2, &prime, &mut rng);
+        let y = generate_shares(&BigUint::from(456u32), 4, 2, &prime, &mut rng);
+        let triple = deal_triple(4, 2, &prime, &mut rng);
+
+        let product = beaver_multiply(&x, &y, &triple, 2, &prime).unwrap();
+        assert_eq!(reconstruct_secret(&product.shares[2..], &prime), BigUint::from(123u32 * 456 % 7919));
+    }
+
+    #[test]
+    fn test_beaver_needs_k_holders_and_matching_triple() {
+        let prime = BigUint::from(7919u32);
+        let mut rng = rand::thread_rng();
+        let x = generate_shares(&BigUint::from(123u32), 4, 3, &prime, &mut rng);
+        let y = generate_shares(&BigUint::from(456u32), 4, 3, &prime, &mut rng);
+
+        let triple = deal_triple(4, 3, &prime, &mut rng);
+        assert!(beaver_multiply(&x[..2], &y[..2], &triple, 3, &prime).is_err());
+
+        let small = deal_triple(3, 3, &prime, &mut rng);
+        assert!(beaver_multiply(&x, &y, &small, 3, &prime).is_err());
+    }
+}
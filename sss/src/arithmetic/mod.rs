@@ -0,0 +1,4 @@
+pub mod share_ops;
+pub mod beaver;
+pub mod refresh;
+pub mod reshare;
@@ -0,0 +1,175 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::polynomial::Polynomial;
+use crate::helper::share_format::format_epoch;
+use super::share_ops::add_shares;
+
+// Proactive refresh: adding a random polynomial with constant term 0 to every
+// share gives a fresh sharing of the same secret, so shares leaked before the
+// refresh are useless together with shares from after it. Each refresh tags
+// the new shares with a random epoch id and k; plain "x,y" shares are epoch 0
+// and carry no k. A counter would not do: two independent refreshes of the
+// same shares would both be "generation 1" and mix without notice.
+
+type Shares = Vec<(BigUint, BigUint)>;
+
+/// A plain share with the epoch it belongs to and, once refreshed, the
+/// threshold it was made for.
+pub struct TaggedShare {
+    pub epoch: u64,
+    pub k: Option<u32>,
+    pub point: (BigUint, BigUint),
+}
+
+/// Shares that all come from one epoch.
+pub struct Generation {
+    pub epoch: u64,
+    pub k: Option<u32>,
+    pub shares: Vec<(BigUint, BigUint)>,
+}
+
+impl Generation {
+    /// Refuses a k that differs from the one the shares were made for.
+    pub fn check_k(&self, k: u32) -> Result<(), String> {
+        match self.k {
+            Some(recorded) if recorded != k => Err(format!(
+                "these shares were made for k = {}, not k = {}",
+                recorded, k
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A random epoch id for a new generation of shares; never 0, which stands
+/// for the original split.
+pub fn new_epoch<R: Rng + ?Sized>(rng: &mut R) -> u64 {
+    loop {
+        let epoch = rng.next_u64();
+        if epoch != 0 {
+            return epoch;
+        }
+    }
+}
+
+/// Random polynomial of degree k - 1 with z(0) = 0.
+pub fn zero_polynomial<R: Rng + ?Sized>(k: u32, prime: &BigUint, rng: &mut R) -> Polynomial {
+    Polynomial::new(&BigUint::from(0u32), k - 1, prime, rng)
+}
+
+fn zero_shares<R: Rng + ?Sized>(shares: &[(BigUint, BigUint)], k: u32, prime: &BigUint, rng: &mut R) -> Shares {
+    let zero = zero_polynomial(k, prime, rng);
+    shares.iter().map(|(x, _)| (x.clone(), zero.evaluate(x, prime))).collect()
+}
+
+/// One party draws the zero-polynomial and updates every share.
+pub fn refresh_shares<R: Rng + ?Sized>(
+    shares: &[(BigUint, BigUint)],
+    k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Result<Shares, String> {
+    add_shares(shares, &zero_shares(shares, k, prime, rng), prime)
+}
+
+/// Every holder draws its own zero-polynomial and sends z_i(x_j) to holder j,
+/// who adds everything it received to its share. No single holder knows the
+/// total update, so no one can link old and new shares.
+pub fn refresh_shares_distributed<R: Rng + ?Sized>(
+    shares: &[(BigUint, BigUint)],
+    k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Result<Shares, String> {
+    let mut refreshed = shares.to_vec();
+    for _ in shares {
+        refreshed = add_shares(&refreshed, &zero_shares(shares, k, prime, rng), prime)?;
+    }
+    Ok(refreshed)
+}
+
+/// All shares must come from the same epoch and threshold; mixing old and
+/// new ones reconstructs garbage.
+pub fn same_generation(tagged: &[TaggedShare]) -> Result<Generation, String> {
+    let first = tagged.first();
+    let epoch = first.map(|share| share.epoch).unwrap_or(0);
+    let k = first.and_then(|share| share.k);
+
+    if let Some(other) = tagged.iter().find(|share| share.epoch != epoch) {
+        return Err(format!(
+            "shares from epoch {} and {} cannot be mixed; use shares from one refresh",
+            format_epoch(epoch),
+            format_epoch(other.epoch)
+        ));
+    }
+    if tagged.iter().any(|share| share.k != k) {
+        return Err(format!("shares of epoch {} disagree on k", format_epoch(epoch)));
+    }
+
+    Ok(Generation {
+        epoch,
+        k,
+        shares: tagged.iter().map(|share| share.point.clone()).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_shares::generate_shares;
+    use crate::reconstruction::reconstruct_secret::reconstruct_secret;
+
+    fn tagged(epoch: u64, k: Option<u32>, shares: &[(BigUint, BigUint)]) -> Vec<TaggedShare> {
+        shares.iter().map(|point| TaggedShare { epoch, k, point: point.clone() }).collect()
+    }
+
+    #[test]
+    fn test_refresh_keeps_the_secret() {
+        let prime = BigUint::from(7919u32);
+        let mut rng = rand::thread_rng();
+        let shares = generate_shares(&BigUint::from(4242u32), 5, 3, &prime, &mut rng);
+
+        for refreshed in [
+            refresh_shares(&shares, 3, &prime, &mut rng).unwrap(),
+            refresh_shares_distributed(&shares, 3, &prime, &mut rng).unwrap(),
+        ] {
+            assert_ne!(refreshed, shares);
+            assert_eq!(reconstruct_secret(&refreshed[2..], &prime), BigUint::from(4242u32));
+        }
+    }
+
+    #[test]
+    fn test_independent_refreshes_get_different_epochs() {
+        let mut rng = rand::thread_rng();
+        let first = new_epoch(&mut rng);
+        let second = new_epoch(&mut rng);
+
+        assert_ne!(first, 0);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_mixing_generations_is_refused() {
+        let prime = BigUint::from(7919u32);
+        let mut rng = rand::thread_rng();
+        let shares = generate_shares(&BigUint::from(4242u32), 4, 2, &prime, &mut rng);
+        let refreshed = refresh_shares(&shares, 2, &prime, &mut rng).unwrap();
+
+        let mut mixed = tagged(0, None, &shares[..1]);
+        mixed.extend(tagged(7, Some(2), &refreshed[1..]));
+        assert!(same_generation(&mixed).is_err());
+
+        let mut two_refreshes = tagged(7, Some(2), &refreshed[..1]);
+        two_refreshes.extend(tagged(8, Some(2), &refreshed[1..]));
+        assert!(same_generation(&two_refreshes).is_err());
+
+        let mut other_k = tagged(7, Some(2), &refreshed[..1]);
+        other_k.extend(tagged(7, Some(3), &refreshed[1..]));
+        assert_eq!(same_generation(&other_k).err().as_deref(), Some("shares of epoch 0000000000000007 disagree on k"));
+
+        let generation = same_generation(&tagged(7, Some(2), &refreshed)).unwrap();
+        assert_eq!((generation.epoch, generation.k), (7, Some(2)));
+        assert!(generation.check_k(2).is_ok());
+        assert!(generation.check_k(3).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::generate_shares::generate_shares;
+use crate::reconstruction::lagrange::compute_lj_zero;
+
+/// Sub-shares one old holder dealt to the new holders.
+pub struct Reshare {
+    pub dealer: BigUint,
+    pub sub_shares: Vec<(BigUint, BigUint)>,
+}
+
+/// Every old holder i shares its own share y_i with `generate_shares` under
+/// (new_n, new_k). Since s = Σ λ_i y_i with λ_i = compute_lj_zero(i), new
+/// holder j's share Σ λ_i h_ij lies on a polynomial of degree new_k - 1 through
+/// s. The old holders must be at least the old threshold; the secret is never
+/// put together anywhere.
+pub fn deal_reshares<R: Rng + ?Sized>(
+    shares: &[(BigUint, BigUint)],
+    new_n: u32,
+    new_k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<Reshare> {
+    shares.iter().map(|share| deal_reshare(share, new_n, new_k, prime, rng)).collect()
+}
+
+/// What one old holder runs on its own share.
+pub fn deal_reshare<R: Rng + ?Sized>(
+    share: &(BigUint, BigUint),
+    new_n: u32,
+    new_k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Reshare {
+    let (x, y) = share;
+    Reshare {
+        dealer: x.clone(),
+        sub_shares: generate_shares(y, new_n, new_k, prime, rng),
+    }
+}
+
+/// What each new holder computes from the sub-shares it received.
+pub fn combine_reshares(
+    shares: &[(BigUint, BigUint)],
+    reshares: &[Reshare],
+    prime: &BigUint,
+) -> Result<Vec<(BigUint, BigUint)>, String> {
+    let dealers: Vec<BigUint> = shares.iter().map(|(x, _)| x.clone()).collect();
+    let Some(first) = reshares.first() else {
+        return Ok(Vec::new());
+    };
+    if reshares.len() != dealers.len()
+        || reshares.iter().any(|reshare| reshare.sub_shares.len() != first.sub_shares.len())
+    {
+        return Err("every old holder must deal one sub-share to every new holder".to_string());
+    }
+
+    (0..first.sub_shares.len())
+        .map(|j| {
+            let received: Vec<BigUint> = reshares.iter().map(|reshare| reshare.sub_shares[j].1.clone()).collect();
+            Ok((first.sub_shares[j].0.clone(), combine_sub_shares(&dealers, &received, prime)?))
+        })
+        .collect()
+}
+
+/// One new holder's share Σ λ_i h_ij, with `sub_shares[i]` from `dealers[i]`
+/// and λ_i taken at 0 over the dealers' x values.
+pub fn combine_sub_shares(dealers: &[BigUint], sub_shares: &[BigUint], prime: &BigUint) -> Result<BigUint, String> {
+    if dealers.len() != sub_shares.len() {
+        return Err(format!("{} old holders but {} sub-shares", dealers.len(), sub_shares.len()));
+    }
+    let mut seen = HashSet::new();
+    if let Some(dealer) = dealers.iter().find(|x| !seen.insert(*x % prime)) {
+        return Err(format!("old holder {} dealt more than once", dealer));
+    }
+
+    let points: Vec<(BigUint, BigUint)> = dealers.iter().cloned().zip(sub_shares.iter().cloned()).collect();
+    Ok((0..points.len()).fold(BigUint::from(0u32), |acc, i| {
+        (acc + &points[i].1 * compute_lj_zero(i, &points, prime)) % prime
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reconstruction::reconstruct_secret::reconstruct_secret;
+
+    fn prime() -> BigUint {
+        BigUint::from(7919u32)
+    }
+
+    #[test]
+    fn test_reshare_moves_the_secret_to_a_new_threshold() {
+        let p = prime();
+        let mut rng = rand::thread_rng();
+        let old = generate_shares(&BigUint::from(5555u32), 5, 3, &p, &mut rng);
+        let dealers = [old[0].clone(), old[2].clone(), old[4].clone()];
+
+        let reshares = deal_reshares(&dealers, 4, 2, &p, &mut rng);
+        let new_shares = combine_reshares(&dealers, &reshares, &p).unwrap();
+
+        assert_eq!(new_shares.len(), 4);
+        assert_eq!(reconstruct_secret(&new_shares[1..3], &p), BigUint::from(5555u32));
+        assert_eq!(reconstruct_secret(&new_shares[2..], &p), BigUint::from(5555u32));
+    }
+
+    #[test]
+    fn test_per_holder_combine_matches_the_simulation() {
+        let p = prime();
+        let mut rng = rand::thread_rng();
+        let old = generate_shares(&BigUint::from(5555u32), 3, 3, &p, &mut rng);
+        let reshares = deal_reshares(&old, 3, 3, &p, &mut rng);
+        let new_shares = combine_reshares(&old, &reshares, &p).unwrap();
+
+        let dealers: Vec<BigUint> = reshares.iter().map(|reshare| reshare.dealer.clone()).collect();
+        let received: Vec<BigUint> = reshares.iter().map(|reshare| reshare.sub_shares[1].1.clone()).collect();
+        assert_eq!(combine_sub_shares(&dealers, &received, &p), Ok(new_shares[1].1.clone()));
+    }
+
+    #[test]
+    fn test_bad_sub_shares_are_refused() {
+        let p = prime();
+        let dealers = [BigUint::from(1u32), BigUint::from(2u32)];
+        let repeated = [BigUint::from(1u32), BigUint::from(7920u32)];
+        let sub_shares = [BigUint::from(10u32), BigUint::from(20u32)];
+
+        assert!(combine_sub_shares(&repeated, &sub_shares, &p).is_err());
+        assert!(combine_sub_shares(&dealers, &sub_shares[..1], &p).is_err());
+
+        let mut rng = rand::thread_rng();
+        let old = generate_shares(&BigUint::from(5555u32), 3, 2, &p, &mut rng);
+        let reshares = deal_reshares(&old[..2], 3, 2, &p, &mut rng);
+        assert!(combine_reshares(&old, &reshares, &p).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::polynomial::Polynomial;
+use crate::reconstruction::lagrange::compute_lj_zero;
+
+// Shamir shares are linear: adding the shares of two secrets (dealt to the
+// same x values) gives shares of their sum, and multiplying by a public
+// constant gives shares of the product, without anyone talking to anyone.
+
+fn same_holders(a: &[(BigUint, BigUint)], b: &[(BigUint, BigUint)]) -> Result<(), String> {
+    if a.len() != b.len() || a.iter().zip(b.iter()).any(|((xa, _), (xb, _))| xa != xb) {
+        return Err("both sharings must be held at the same x values".to_string());
+    }
+    Ok(())
+}
+
+pub fn add_shares(
+    a: &[(BigUint, BigUint)],
+    b: &[(BigUint, BigUint)],
+    prime: &BigUint,
+) -> Result<Vec<(BigUint, BigUint)>, String> {
+    same_holders(a, b)?;
+    Ok(a.iter()
+        .zip(b.iter())
+        .map(|((x, ya), (_, yb))| (x.clone(), (ya + yb) % prime))
+        .collect())
+}
+
+pub fn sub_shares(
+    a: &[(BigUint, BigUint)],
+    b: &[(BigUint, BigUint)],
+    prime: &BigUint,
+) -> Result<Vec<(BigUint, BigUint)>, String> {
+    same_holders(a, b)?;
+    Ok(a.iter()
+        .zip(b.iter())
+        .map(|((x, ya), (_, yb))| (x.clone(), (ya + prime - yb % prime) % prime))
+        .collect())
+}
+
+pub fn scale_shares(a: &[(BigUint, BigUint)], c: &BigUint, prime: &BigUint) -> Vec<(BigUint, BigUint)> {
+    a.iter().map(|(x, y)| (x.clone(), (y * c) % prime)).collect()
+}
+
+/// Adds a public constant to the shared secret: every share moves by c, since
+/// f(x) + c is a polynomial of the same degree with constant term s + c.
+pub fn add_constant(a: &[(BigUint, BigUint)], c: &BigUint, prime: &BigUint) -> Vec<(BigUint, BigUint)> {
+    a.iter().map(|(x, y)| (x.clone(), (y + c) % prime)).collect()
+}
+
+/// Multiplies share by share. The result is a sharing of the product, but on a
+/// polynomial of degree 2(k - 1), so it needs 2k - 1 shares until reduced.
+pub fn multiply_local(
+    a: &[(BigUint, BigUint)],
+    b: &[(BigUint, BigUint)],
+    prime: &BigUint,
+) -> Result<Vec<(BigUint, BigUint)>, String> {
+    same_holders(a, b)?;
+    Ok(a.iter()
+        .zip(b.iter())
+        .map(|((x, ya), (_, yb))| (x.clone(), (ya * yb) % prime))
+        .collect())
+}
+
+/// Brings a degree 2(k - 1) sharing back to degree k - 1 (Gennaro–Rabin–Rabin).
+/// The first 2k - 1 holders each re-share their share with threshold k, and
+/// every holder combines the pieces it received with the Lagrange weights at zero.
+pub fn reduce_degree<R: Rng + ?Sized>(
+    shares: &[(BigUint, BigUint)],
+    k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Result<Vec<(BigUint, BigUint)>, String> {
+    let needed = k
+        .checked_mul(2)
+        .and_then(|twice| twice.checked_sub(1))
+        .ok_or_else(|| format!("k = {} is out of range for degree reduction", k))? as usize;
+    if shares.len() < needed {
+        return Err(format!("degree reduction needs 2k - 1 = {} holders, got {}", needed, shares.len()));
+    }
+    let dealers = &shares[..needed];
+
+    let mut reduced: Vec<(BigUint, BigUint)> = shares
+        .iter()
+        .map(|(x, _)| (x.clone(), BigUint::from(0u32)))
+        .collect();
+    for (i, (_, y)) in dealers.iter().enumerate() {
+        let weight = compute_lj_zero(i, dealers, prime);
+        let poly = Polynomial::new(y, k - 1, prime, rng);
+        for (x, total) in reduced.iter_mut() {
+            *total = (&*total + poly.evaluate(x, prime) * &weight) % prime;
+        }
+    }
+
+    Ok(reduced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_shares::generate_shares;
+    use crate::reconstruction::reconstruct_secret::reconstruct_secret;
+
+    fn prime() -> BigUint {
+        BigUint::from(7919u32)
+    }
+
+    fn share(secret: u32) -> Vec<(BigUint, BigUint)> {
+        generate_shares(&BigUint::from(secret), 5, 3, &prime(), &mut rand::thread_rng())
+    }
+
+    #[test]
+    fn test_linear_operations() {
+        let (a, b) = (share(100), share(7900));
+        let p = prime();
+
+        assert_eq!(reconstruct_secret(&add_shares(&a, &b, &p).unwrap()[..3], &p), BigUint::from(81u32));
+        assert_eq!(reconstruct_secret(&sub_shares(&a, &b, &p).unwrap()[2..], &p), BigUint::from(119u32));
+        assert_eq!(reconstruct_secret(&scale_shares(&a, &BigUint::from(3u32), &p)[..3], &p), BigUint::from(300u32));
+        assert_eq!(reconstruct_secret(&add_constant(&a, &BigUint::from(5u32), &p)[..3], &p), BigUint::from(105u32));
+    }
+
+    #[test]
+    fn test_different_holders_are_refused() {
+        let (a, b) = (share(1), share(2));
+        let p = prime();
+
+        assert!(add_shares(&a, &b[..4], &p).is_err());
+        assert!(multiply_local(&a[1..], &b[..4], &p).is_err());
+    }
+
+    #[test]
+    fn test_local_product_needs_degree_reduction() {
+        let (a, b) = (share(12), share(34));
+        let p = prime();
+        let local = multiply_local(&a, &b, &p).unwrap();
+
+        assert_eq!(reconstruct_secret(&local, &p), BigUint::from(408u32));
+        let reduced = reduce_degree(&local, 3, &p, &mut rand::thread_rng()).unwrap();
+        assert_eq!(reconstruct_secret(&reduced[2..], &p), BigUint::from(408u32));
+
+        assert!(reduce_degree(&local[..4], 3, &p, &mut rand::thread_rng()).is_err());
+        assert!(reduce_degree(&local, 0, &p, &mut rand::thread_rng()).is_err());
+        assert!(reduce_degree(&local, u32::MAX, &p, &mut rand::thread_rng()).is_err());
+    }
+}
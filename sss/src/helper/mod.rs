@@ -1 +1,3 @@
-pub mod secret_generation;
\ No newline at end of file
+pub mod secret_generation;
+pub mod share_format;
+pub mod seed;
@@ -4,8 +4,8 @@ pub fn string_to_biguint(s: &str) -> BigUint {
     let mut result = BigUint::from(0u32);
     
     for ch in s.chars() {
-        result = result * BigUint::from(256u32);
-        result = result + BigUint::from(ch as u32);
+        result *= BigUint::from(256u32);
+        result += BigUint::from(ch as u32);
     }
     
     result
@@ -18,10 +18,84 @@ pub fn biguint_to_string(n: &BigUint) -> String {
     while n > BigUint::from(0u32) {
         let byte = (&n % BigUint::from(256u32)).to_u32_digits()[0] as u8;
         bytes.push(byte);
-        n = n / BigUint::from(256u32);
+        n /= BigUint::from(256u32);
     }
     
     bytes.reverse();
     
     String::from_utf8_lossy(&bytes).to_string()
-}
\ No newline at end of file
+}
+
+/// Bytes per chunk: the most whole bytes that always stay below the prime.
+fn chunk_bytes(prime: &BigUint) -> Result<usize, String> {
+    if prime.bits() < 9 {
+        return Err(format!("The prime {} is too small to hold a byte per chunk; use one of at least 9 bits", prime));
+    }
+    Ok(((prime.bits() - 1) / 8) as usize)
+}
+
+/// Cuts the secret's bytes into fixed-width chunks that each fit below the
+/// prime. The bytes are padded with 0x80 and then zeros up to `count` full
+/// chunks, so NUL bytes in the secret survive the round trip. Fails if the
+/// prime cannot hold a single byte per chunk or the secret needs more than
+/// `count` chunks.
+pub fn string_to_chunks(s: &str, count: usize, prime: &BigUint) -> Result<Vec<BigUint>, String> {
+    let width = chunk_bytes(prime)?;
+    if s.len() + 1 > count * width {
+        return Err(format!("Secret is too long for {} chunks; raise k or lower --ramp", count));
+    }
+
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.push(0x80);
+    bytes.resize(count * width, 0);
+    Ok(bytes.chunks(width).map(BigUint::from_bytes_be).collect())
+}
+
+/// Inverse of `string_to_chunks`: lays every chunk out at its fixed width and
+/// strips the 0x80 padding.
+pub fn chunks_to_string(chunks: &[BigUint], prime: &BigUint) -> Result<String, String> {
+    let width = chunk_bytes(prime)?;
+    let mut bytes = Vec::with_capacity(chunks.len() * width);
+    for chunk in chunks {
+        let chunk = chunk.to_bytes_be();
+        if chunk.len() > width {
+            return Err("a chunk is wider than the prime allows; wrong shares or prime?".to_string());
+        }
+        bytes.resize(bytes.len() + width - chunk.len(), 0);
+        bytes.extend(chunk);
+    }
+
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    if bytes.pop() != Some(0x80) {
+        return Err("the chunks carry no end marker; wrong shares or prime?".to_string());
+    }
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_roundtrip_keeps_nul_bytes() {
+        let prime = BigUint::from(340282366920938463463374607431768211297u128);
+        for secret in ["", "abc", "\0\0lead", "trail\0\0", "a\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0b", "fifteen bytes!!"] {
+            let chunks = string_to_chunks(secret, 3, &prime).unwrap();
+            assert_eq!(chunks.len(), 3);
+            assert_eq!(chunks_to_string(&chunks, &prime), Ok(secret.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_chunk_limits() {
+        let prime = BigUint::from(257u32);
+        assert!(string_to_chunks("abc", 3, &prime).is_err());
+        assert_eq!(chunks_to_string(&string_to_chunks("ab", 3, &prime).unwrap(), &prime), Ok("ab".to_string()));
+        assert!(string_to_chunks("a", 2, &BigUint::from(251u32)).is_err());
+
+        assert!(chunks_to_string(&[BigUint::from(300u32)], &prime).is_err());
+        assert!(chunks_to_string(&[BigUint::from(0u32)], &prime).is_err());
+    }
+}
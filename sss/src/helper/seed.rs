@@ -0,0 +1,13 @@
+/// "00112233..." (64 hex digits) -> the 32-byte ChaCha20 seed.
+pub fn parse_seed_hex(s: &str) -> Result<[u8; 32], String> {
+    let s = s.trim();
+    if s.len() != 64 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("--seed-hex must be exactly 64 hex digits (32 bytes)".to_string());
+    }
+
+    let mut seed = [0u8; 32];
+    for (i, byte) in seed.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).expect("checked to be hex digits");
+    }
+    Ok(seed)
+}
@@ -0,0 +1,278 @@
+use num_bigint::BigUint;
+use crate::arithmetic::refresh::TaggedShare;
+use crate::polynomial::generate_hierarchical_shares::HierarchicalShare;
+use crate::polynomial::generate_packed_shares::PackedShare;
+use crate::polynomial::generate_policy_shares::PolicyShare;
+use crate::polynomial::generate_ramp_shares::RampShare;
+use crate::polynomial::generate_weighted_shares::WeightedShare;
+
+// CLI formats:
+//   plain shares     "x1,y1;x2,y2", after a refresh "gE/k:x1,y1;gE/k:x2,y2"
+//                    where E is the 16-digit hex epoch id
+//   weighted shares  "name:k:x1,y1|x2,y2;name:k:x3,y3"
+//   hierarchical     "k0/k1:level:x,y;k0/k1:level:x,y"
+//   policy shares    "name[path]=y;name[path]=y", e.g. "Alice[0.1]=42"
+//   ramp shares      "t/k:x,y;t/k:x,y"
+//   packed shares    "l/k:x,y;l/k:x,y"
+//   reshare pieces   "dealer>gE/k:x,y;dealer>gE/k:x,y"
+
+fn parse_number(s: &str) -> Result<BigUint, String> {
+    BigUint::parse_bytes(s.trim().as_bytes(), 10).ok_or_else(|| format!("'{}' is not a number", s.trim()))
+}
+
+pub fn parse_point(s: &str) -> Result<(BigUint, BigUint), String> {
+    let parts: Vec<&str> = s.split(',').collect();
+    if parts.len() != 2 {
+        return Err(format!("share '{}' must look like x,y", s.trim()));
+    }
+    Ok((parse_number(parts[0])?, parse_number(parts[1])?))
+}
+
+/// An epoch id as written in tags: up to 16 hex digits.
+pub fn parse_epoch(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    if s.is_empty() || s.len() > 16 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("epoch '{}' must be up to 16 hex digits", s));
+    }
+    u64::from_str_radix(s, 16).map_err(|_| format!("epoch '{}' must be up to 16 hex digits", s))
+}
+
+/// Epoch 0 is the original split; every other epoch prints as 16 hex digits.
+pub fn format_epoch(epoch: u64) -> String {
+    if epoch == 0 { "0".to_string() } else { format!("{:016x}", epoch) }
+}
+
+/// Plain shares with an optional "gE/k:" tag (or "gE:" without a threshold);
+/// untagged is epoch 0.
+pub fn parse_tagged_shares(s: &str) -> Result<Vec<TaggedShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| match part.trim().split_once(':') {
+            Some((tag, point)) => {
+                let malformed = || format!("share '{}' must look like x,y or gE/k:x,y", part.trim());
+                let tag = tag.strip_prefix('g').ok_or_else(malformed)?;
+                let (epoch, k) = match tag.split_once('/') {
+                    Some((epoch, k)) => (epoch, Some(k.parse::<u32>().map_err(|_| malformed())?)),
+                    None => (tag, None),
+                };
+                let epoch = parse_epoch(epoch).map_err(|_| malformed())?;
+                Ok(TaggedShare { epoch, k, point: parse_point(point)? })
+            }
+            None => Ok(TaggedShare { epoch: 0, k: None, point: parse_point(part)? }),
+        })
+        .collect()
+}
+
+pub fn format_tagged_shares(epoch: u64, k: u32, shares: &[(BigUint, BigUint)]) -> String {
+    if epoch == 0 {
+        return format_shares(shares);
+    }
+    shares
+        .iter()
+        .map(|(x, y)| format!("g{}/{}:{},{}", format_epoch(epoch), k, x, y))
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+/// Sub-shares a new holder received while resharing, each as
+/// "dealer>gE/k:x,y" with the tag of the new epoch.
+pub fn parse_sub_shares(s: &str) -> Result<Vec<(BigUint, TaggedShare)>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let (dealer, share) = part
+                .trim()
+                .split_once('>')
+                .ok_or_else(|| format!("sub-share '{}' must look like dealer>gE/k:x,y", part.trim()))?;
+            let mut tagged = parse_tagged_shares(share)?;
+            if tagged.len() != 1 {
+                return Err(format!("sub-share '{}' must hold exactly one share", part.trim()));
+            }
+            Ok((parse_number(dealer)?, tagged.remove(0)))
+        })
+        .collect()
+}
+
+pub fn format_sub_share(dealer: &BigUint, epoch: u64, k: u32, share: &(BigUint, BigUint)) -> String {
+    format!("{}>{}", dealer, format_tagged_shares(epoch, k, std::slice::from_ref(share)))
+}
+
+pub fn format_shares(shares: &[(BigUint, BigUint)]) -> String {
+    shares
+        .iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+pub fn parse_weighted_shares(s: &str) -> Result<Vec<WeightedShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let fields: Vec<&str> = part.trim().splitn(3, ':').collect();
+            if fields.len() != 3 {
+                return Err(format!("weighted share '{}' must look like name:k:x,y|x,y", part.trim()));
+            }
+            let threshold = fields[1]
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| format!("'{}' is not a threshold", fields[1].trim()))?;
+            let points = fields[2]
+                .split('|')
+                .map(parse_point)
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(WeightedShare {
+                participant: fields[0].trim().to_string(),
+                threshold,
+                points,
+            })
+        })
+        .collect()
+}
+
+pub fn format_weighted_share(share: &WeightedShare) -> String {
+    let points: Vec<String> = share.points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    format!("{}:{}:{}", share.participant, share.threshold, points.join("|"))
+}
+
+/// "ceo=2,alice=1,bob=1" -> [("ceo", 2), ("alice", 1), ("bob", 1)]
+pub fn parse_weights(s: &str) -> Result<Vec<(String, u32)>, String> {
+    let mut participants: Vec<(String, u32)> = Vec::new();
+    for part in s.split(',') {
+        let (name, weight) = part
+            .split_once('=')
+            .ok_or_else(|| format!("participant '{}' must look like name=weight", part.trim()))?;
+        let name = name.trim();
+        if name.is_empty() || name.contains([':', ';', '|']) {
+            return Err(format!("participant name '{}' must be non-empty without ':', ';' or '|'", name));
+        }
+        if participants.iter().any(|(seen, _)| seen == name) {
+            return Err(format!("participant '{}' is listed more than once", name));
+        }
+        let weight = weight
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("'{}' is not a weight", weight.trim()))?;
+        if weight == 0 {
+            return Err(format!("participant '{}' must have a weight above zero", name));
+        }
+        participants.push((name.to_string(), weight));
+    }
+    Ok(participants)
+}
+
+/// "1,3" -> [1, 3]
+pub fn parse_u32_list(s: &str) -> Result<Vec<u32>, String> {
+    s.split(',')
+        .map(|part| part.trim().parse::<u32>().map_err(|_| format!("'{}' is not a number", part.trim())))
+        .collect()
+}
+
+/// Cumulative level thresholds, which must start above zero and strictly increase.
+pub fn parse_levels(s: &str) -> Result<Vec<u32>, String> {
+    let thresholds = parse_u32_list(&s.replace('/', ","))?;
+    if thresholds.first().is_none_or(|&first| first == 0)
+        || thresholds.windows(2).any(|pair| pair[0] >= pair[1])
+    {
+        return Err(format!("levels '{}' must be increasing thresholds above zero, e.g. 1,3", s));
+    }
+    Ok(thresholds)
+}
+
+pub fn parse_hierarchical_shares(s: &str) -> Result<Vec<HierarchicalShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let fields: Vec<&str> = part.trim().splitn(3, ':').collect();
+            if fields.len() != 3 {
+                return Err(format!("hierarchical share '{}' must look like k0/k1:level:x,y", part.trim()));
+            }
+            let thresholds = parse_levels(fields[0])?;
+            let level = fields[1]
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("'{}' is not a level", fields[1].trim()))?;
+            let (x, y) = parse_point(fields[2])?;
+            Ok(HierarchicalShare { thresholds, level, x, y })
+        })
+        .collect()
+}
+
+pub fn format_hierarchical_share(share: &HierarchicalShare) -> String {
+    let thresholds: Vec<String> = share.thresholds.iter().map(|k| k.to_string()).collect();
+    format!("{}:{}:{},{}", thresholds.join("/"), share.level, share.x, share.y)
+}
+
+/// [0, 1] -> "0.1"; the root is the empty path.
+pub fn format_path(path: &[usize]) -> String {
+    path.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(".")
+}
+
+pub fn parse_policy_shares(s: &str) -> Result<Vec<PolicyShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let malformed = || format!("policy share '{}' must look like name[path]=y", part.trim());
+            let (leaf, value) = part.trim().split_once('=').ok_or_else(malformed)?;
+            let (party, path) = leaf.strip_suffix(']').and_then(|leaf| leaf.split_once('[')).ok_or_else(malformed)?;
+            let path = path
+                .split('.')
+                .filter(|i| !i.is_empty())
+                .map(|i| i.parse::<usize>().map_err(|_| malformed()))
+                .collect::<Result<Vec<usize>, String>>()?;
+            Ok(PolicyShare {
+                party: party.trim().to_string(),
+                path,
+                value: parse_number(value)?,
+            })
+        })
+        .collect()
+}
+
+pub fn format_policy_share(share: &PolicyShare) -> String {
+    format!("{}[{}]={}", share.party, format_path(&share.path), share.value)
+}
+
+pub fn parse_ramp_shares(s: &str) -> Result<Vec<RampShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let malformed = || format!("ramp share '{}' must look like t/k:x,y", part.trim());
+            let (params, point) = part.trim().split_once(':').ok_or_else(malformed)?;
+            let (t, k) = params.split_once('/').ok_or_else(malformed)?;
+            let t = t.trim().parse::<u32>().map_err(|_| malformed())?;
+            let k = k.trim().parse::<u32>().map_err(|_| malformed())?;
+            if t == 0 || t > k {
+                return Err(format!("ramp share '{}' needs 1 <= t <= k", part.trim()));
+            }
+            let (x, y) = parse_point(point)?;
+            Ok(RampShare { t, k, x, y })
+        })
+        .collect()
+}
+
+pub fn format_ramp_share(share: &RampShare) -> String {
+    format!("{}/{}:{},{}", share.t, share.k, share.x, share.y)
+}
+
+pub fn parse_packed_shares(s: &str) -> Result<Vec<PackedShare>, String> {
+    s.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let malformed = || format!("packed share '{}' must look like l/k:x,y", part.trim());
+            let (params, point) = part.trim().split_once(':').ok_or_else(malformed)?;
+            let (l, k) = params.split_once('/').ok_or_else(malformed)?;
+            let l = l.trim().parse::<u32>().map_err(|_| malformed())?;
+            let k = k.trim().parse::<u32>().map_err(|_| malformed())?;
+            if l == 0 {
+                return Err(format!("packed share '{}' needs at least one secret", part.trim()));
+            }
+            let (x, y) = parse_point(point)?;
+            Ok(PackedShare { l, k, x, y })
+        })
+        .collect()
+}
+
+pub fn format_packed_share(share: &PackedShare) -> String {
+    format!("{}/{}:{},{}", share.l, share.k, share.x, share.y)
+}
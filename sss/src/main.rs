@@ -1,70 +1,586 @@
 use clap::{Parser};
 mod helper;
 use helper::secret_generation;
+use helper::seed::parse_seed_hex;
+use helper::share_format::{
+    format_epoch, format_hierarchical_share, format_packed_share, format_policy_share, format_ramp_share,
+    format_shares, format_sub_share, format_tagged_shares, format_weighted_share, parse_hierarchical_shares, parse_levels,
+    parse_epoch, parse_packed_shares, parse_policy_shares, parse_ramp_shares, parse_sub_shares, parse_tagged_shares, parse_u32_list,
+    parse_weighted_shares, parse_weights,
+};
 mod models;
 use models::{Args, Commands};
 mod reconstruction;
-use reconstruction::{reconstruct_secret::reconstruct_secret};
-use secret_generation::{string_to_biguint, biguint_to_string};
+use reconstruction::{
+    interpolate::interpolate_threshold,
+    reconstruct_secret::reconstruct_secret,
+    reconstruct_weighted::reconstruct_weighted,
+    reconstruct_hierarchical::{is_authorized, reconstruct_hierarchical},
+    reconstruct_policy::reconstruct_policy,
+    reconstruct_ramp::reconstruct_ramp,
+    reconstruct_packed::reconstruct_packed,
+};
+use secret_generation::{string_to_biguint, biguint_to_string, string_to_chunks, chunks_to_string};
 use num_bigint::BigUint;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 
 mod polynomial;
 use polynomial::{
-    generate_shares::generate_shares
+    generate_shares::generate_shares,
+    generate_weighted_shares::{generate_weighted_shares, total_weight},
+    generate_hierarchical_shares::generate_hierarchical_shares,
+    generate_policy_shares::generate_policy_shares,
+    generate_ramp_shares::{generate_ramp_shares, ramp_chunks},
+    generate_packed_shares::generate_packed_shares,
+};
+mod policy;
+use policy::parser::parse_policy;
+mod arithmetic;
+use arithmetic::{
+    beaver::{beaver_multiply, deal_triple},
+    refresh::{new_epoch, refresh_shares, refresh_shares_distributed, same_generation},
+    reshare::{combine_reshares, combine_sub_shares, deal_reshare, deal_reshares},
+    share_ops::{add_shares, multiply_local, reduce_degree, scale_shares},
 };
 
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
+
 fn main() {
     let args = Args::parse();
 
+    let mut rng: Box<dyn RngCore> = match &args.seed_hex {
+        Some(hex) => {
+            let seed = parse_seed_hex(hex).unwrap_or_else(|e| fail(&e));
+            eprintln!("WARNING: --seed-hex makes every share predictable. Never use it for real secrets.");
+            Box::new(ChaCha20Rng::from_seed(seed))
+        }
+        None => Box::new(rand::thread_rng()),
+    };
+
     match args.cmd {
-        Commands::Split { secret, n, k, p } => {
+        Commands::Split { secret, n, k, p, ramp: Some(t) } => {
+            let prime = BigUint::from(p);
+
+            if t == 0 || t > k || k > n {
+                fail("Ramp parameters must satisfy 1 <= t <= k <= n");
+            }
+            let count = ramp_chunks(t, k);
+            let chunks = string_to_chunks(&secret, count, &prime).unwrap_or_else(|e| fail(&e));
+
+            let shares = generate_ramp_shares(&chunks, t, k, n, &prime, &mut rng);
+
+            println!("\nRamp ({}, {}, {}): secret cut into {} chunks", t, k, n, count);
+            println!("Fewer than {} shares reveal nothing, {} reconstruct, and in between part of the secret leaks", t, k);
+            println!("\nGenerated Shares");
+            for (i, share) in shares.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, share.x, share.y);
+            }
+
+            let cli_format: Vec<String> = shares.iter().map(format_ramp_share).collect();
+            println!("\nCLI Ready Format (reconstruct with --ramp)");
+            println!("--shares \"{}\"", cli_format.join(";"));
+        }
+
+        Commands::Split { secret, n, k, p, ramp: None } => {
             let secret_num = string_to_biguint(&secret);
             let prime = BigUint::from(p);
 
+            if k == 0 {
+                fail("k must be at least 1");
+            }
             if secret_num >= prime {
-                eprintln!("Secret must be smaller than prime");
-                std::process::exit(1);
+                fail("Secret must be smaller than prime");
             }
 
-            let shares = generate_shares(&secret_num, n, k, &prime);
+            let shares = generate_shares(&secret_num, n, k, &prime, &mut rng);
 
             println!("\nGenerated Shares");
             for (i, (x, y)) in shares.iter().enumerate() {
                 println!("Share {} -> x: {}, y: {}", i + 1, x, y);
             }
 
-            let cli_format: Vec<String> =
-                shares.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
-
-            let cli_string = cli_format.join(";");
-
             println!("\nCLI Ready Format");
-            println!("--shares \"{}\"", cli_string);
+            println!("--shares \"{}\"", format_shares(&shares));
         }
 
-        Commands::Reconstruct { prime, shares } => {
+        Commands::Reconstruct { prime, shares, ramp: true } => {
             let p = BigUint::from(prime);
-            let mut shares_vec = Vec::new();
+            let shares_vec = parse_ramp_shares(&shares).unwrap_or_else(|e| fail(&e));
+
+            println!("\nGiven Shares");
+            for (i, share) in shares_vec.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, share.x, share.y);
+            }
 
-            for s in shares.split(';') {
-                let s = s.trim();
-                if s.is_empty() {
-                    continue;
+            match reconstruct_ramp(&shares_vec, &p).and_then(|chunks| chunks_to_string(&chunks, &p)) {
+                Ok(secret_str) => {
+                    println!("\nThe Reconstructed String is : {}", secret_str);
                 }
-                let parts: Vec<&str> = s.split(',').collect();
-                let x = BigUint::parse_bytes(parts[0].trim().as_bytes(), 10).unwrap();
-                let y = BigUint::parse_bytes(parts[1].trim().as_bytes(), 10).unwrap();
-                shares_vec.push((x, y));
+                Err(e) => fail(&format!("\nCannot reconstruct: {}", e)),
+            }
+        }
+
+        Commands::Reconstruct { prime, shares, ramp: false } => {
+            let p = BigUint::from(prime);
+            let tagged = parse_tagged_shares(&shares).unwrap_or_else(|e| fail(&e));
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+            let shares_vec = generation.shares;
+
+            if let Some(k) = generation.k
+                && shares_vec.len() < k as usize
+            {
+                fail(&format!("These shares were made for k = {}; got only {}", k, shares_vec.len()));
             }
 
             let secret_num = reconstruct_secret(&shares_vec, &p);
             let secret_str = biguint_to_string(&secret_num);
 
-            println!("\nGiven Shares");
+            println!("\nGiven Shares (epoch {})", format_epoch(generation.epoch));
             for (i, (x, y)) in shares_vec.iter().enumerate() {
                 println!("Share {} -> x: {}, y: {}", i + 1, x, y);
             }
             println!("\nThe Reconstructed String is : {}", secret_str);
         }
+
+        Commands::SplitWeighted { secret, participants, k, p } => {
+            let secret_num = string_to_biguint(&secret);
+            let prime = BigUint::from(p);
+            let weights = parse_weights(&participants).unwrap_or_else(|e| fail(&e));
+
+            if secret_num >= prime {
+                fail("Secret must be smaller than prime");
+            }
+            let total = total_weight(&weights).unwrap_or_else(|e| fail(&e));
+            if k == 0 || total < k {
+                fail(&format!("k must be between 1 and the total weight {}", total));
+            }
+
+            let shares = generate_weighted_shares(&secret_num, &weights, k, &prime, &mut rng);
+
+            println!("\nGenerated Weighted Shares (threshold weight {})", k);
+            for share in &shares {
+                println!("{} (weight {}):", share.participant, share.weight());
+                for (x, y) in &share.points {
+                    println!("    x: {}, y: {}", x, y);
+                }
+            }
+
+            println!("\nCLI Ready Format (one per participant)");
+            for share in &shares {
+                println!("{}", format_weighted_share(share));
+            }
+        }
+
+        Commands::ReconstructWeighted { prime, shares } => {
+            let p = BigUint::from(prime);
+            let shares_vec = parse_weighted_shares(&shares).unwrap_or_else(|e| fail(&e));
+
+            println!("\nGiven Participants");
+            for share in &shares_vec {
+                println!("{} -> weight {}", share.participant, share.weight());
+            }
+
+            match reconstruct_weighted(&shares_vec, &p) {
+                Ok(secret_num) => {
+                    println!("\nThe Reconstructed String is : {}", biguint_to_string(&secret_num));
+                }
+                Err(e) => fail(&format!("\nCannot reconstruct: {}", e)),
+            }
+        }
+
+        Commands::SplitHierarchical { secret, levels, counts, p } => {
+            let secret_num = string_to_biguint(&secret);
+            let prime = BigUint::from(p);
+            let thresholds = parse_levels(&levels).unwrap_or_else(|e| fail(&e));
+            let counts = parse_u32_list(&counts).unwrap_or_else(|e| fail(&e));
+
+            if secret_num >= prime {
+                fail("Secret must be smaller than prime");
+            }
+            if counts.len() != thresholds.len() {
+                fail("Give one member count per level");
+            }
+            let everyone: Vec<usize> = counts
+                .iter()
+                .enumerate()
+                .flat_map(|(level, count)| std::iter::repeat_n(level, *count as usize))
+                .collect();
+            if let Err(e) = is_authorized(&thresholds, &everyone) {
+                fail(&format!("Even all members together could not reconstruct: {}", e));
+            }
+
+            let shares = generate_hierarchical_shares(&secret_num, &thresholds, &counts, &prime, &mut rng);
+
+            println!("\nGenerated Hierarchical Shares");
+            for share in &shares {
+                println!(
+                    "Level {} (derivative {}) -> x: {}, y: {}",
+                    share.level,
+                    share.derivative_order(),
+                    share.x,
+                    share.y
+                );
+            }
+
+            let cli_format: Vec<String> = shares.iter().map(format_hierarchical_share).collect();
+            println!("\nCLI Ready Format");
+            println!("--shares \"{}\"", cli_format.join(";"));
+        }
+
+        Commands::ReconstructHierarchical { prime, shares } => {
+            let p = BigUint::from(prime);
+            let shares_vec = parse_hierarchical_shares(&shares).unwrap_or_else(|e| fail(&e));
+
+            println!("\nGiven Shares");
+            for share in &shares_vec {
+                println!("Level {} -> x: {}, y: {}", share.level, share.x, share.y);
+            }
+
+            match reconstruct_hierarchical(&shares_vec, &p) {
+                Ok(secret_num) => {
+                    println!("\nThe Reconstructed String is : {}", biguint_to_string(&secret_num));
+                }
+                Err(e) => fail(&format!("\nCannot reconstruct: {}", e)),
+            }
+        }
+
+        Commands::CheckHierarchical { levels, members } => {
+            let thresholds = parse_levels(&levels).unwrap_or_else(|e| fail(&e));
+            let members: Vec<usize> = parse_u32_list(&members)
+                .unwrap_or_else(|e| fail(&e))
+                .into_iter()
+                .map(|level| level as usize)
+                .collect();
+            if members.iter().any(|&level| level >= thresholds.len()) {
+                fail("A member names a level that does not exist");
+            }
+
+            match is_authorized(&thresholds, &members) {
+                Ok(()) => println!("Authorized"),
+                Err(e) => {
+                    println!("Not authorized: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::SplitPolicy { secret, policy, p } => {
+            let secret_num = string_to_biguint(&secret);
+            let prime = BigUint::from(p);
+            let policy = parse_policy(&policy).unwrap_or_else(|e| fail(&format!("Invalid policy: {}", e)));
+
+            if secret_num >= prime {
+                fail("Secret must be smaller than prime");
+            }
+
+            let shares = generate_policy_shares(&secret_num, &policy, &prime, &mut rng);
+
+            println!("\nPolicy: {}", policy);
+            println!("\nGenerated Policy Shares");
+            for share in &shares {
+                println!("{} -> {}", share.party, format_policy_share(share));
+            }
+
+            let cli_format: Vec<String> = shares.iter().map(format_policy_share).collect();
+            println!("\nCLI Ready Format (give each party the entries with its name)");
+            println!("--shares \"{}\"", cli_format.join(";"));
+        }
+
+        Commands::ReconstructPolicy { prime, policy, shares } => {
+            let p = BigUint::from(prime);
+            let policy = parse_policy(&policy).unwrap_or_else(|e| fail(&format!("Invalid policy: {}", e)));
+            let shares_vec = parse_policy_shares(&shares).unwrap_or_else(|e| fail(&e));
+
+            println!("\nPolicy: {}", policy);
+            println!("\nGiven Shares");
+            for share in &shares_vec {
+                println!("{} -> {}", share.party, share.value);
+            }
+
+            match reconstruct_policy(&shares_vec, &policy, &p) {
+                Ok(Some(secret_num)) => {
+                    println!("\nPolicy satisfied");
+                    println!("\nThe Reconstructed String is : {}", biguint_to_string(&secret_num));
+                }
+                Ok(None) => fail("\nPolicy not satisfied by the given shares"),
+                Err(e) => fail(&format!("\nCannot reconstruct: {}", e)),
+            }
+        }
+
+        Commands::CheckPolicy { policy, members } => {
+            let policy = parse_policy(&policy).unwrap_or_else(|e| fail(&format!("Invalid policy: {}", e)));
+            let members: Vec<String> = members
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect();
+
+            if policy.is_satisfied(&members) {
+                println!("Authorized");
+            } else {
+                println!("Not authorized: {} does not satisfy {}", members.join(", "), policy);
+                std::process::exit(1);
+            }
+        }
+
+        Commands::SplitPacked { secrets, n, k, p } => {
+            let prime = BigUint::from(p);
+            let secret_nums: Vec<BigUint> = secrets.split(',').map(|secret| string_to_biguint(secret.trim())).collect();
+            let l = secret_nums.len() as u32;
+
+            if k == 0 {
+                fail("k must be at least 1");
+            }
+            if secret_nums.iter().any(|secret| *secret >= prime) {
+                fail("Every secret must be smaller than prime");
+            }
+            let Some(kl) = k.checked_add(l) else {
+                fail("k + l is too large");
+            };
+            if kl > n {
+                fail(&format!("Need at least k + l = {} holders to ever reconstruct", kl));
+            }
+            if BigUint::from(n) + kl > prime {
+                fail(&format!(
+                    "Need n + k + l <= prime, or holder x values collide with the fixed points -j mod {}",
+                    prime
+                ));
+            }
+
+            let shares = generate_packed_shares(&secret_nums, k, n, &prime, &mut rng);
+
+            println!("\nPacked {} secrets: any {} shares reveal nothing, {} recover the batch", l, k, kl);
+            println!("\nGenerated Shares");
+            for (i, share) in shares.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, share.x, share.y);
+            }
+
+            let cli_format: Vec<String> = shares.iter().map(format_packed_share).collect();
+            println!("\nCLI Ready Format");
+            println!("--shares \"{}\"", cli_format.join(";"));
+        }
+
+        Commands::ReconstructPacked { prime, shares } => {
+            let p = BigUint::from(prime);
+            let shares_vec = parse_packed_shares(&shares).unwrap_or_else(|e| fail(&e));
+
+            println!("\nGiven Shares");
+            for (i, share) in shares_vec.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, share.x, share.y);
+            }
+
+            match reconstruct_packed(&shares_vec, &p) {
+                Ok(secret_nums) => {
+                    println!();
+                    for (j, secret_num) in secret_nums.iter().enumerate() {
+                        println!("Secret {} : {}", j + 1, biguint_to_string(secret_num));
+                    }
+                }
+                Err(e) => fail(&format!("\nCannot reconstruct: {}", e)),
+            }
+        }
+
+        Commands::MpcDemo { a, b, c, n, k, p } => {
+            let prime = BigUint::from(p);
+            let (a, b, c) = (BigUint::from(a), BigUint::from(b), BigUint::from(c));
+
+            if a >= prime || b >= prime {
+                fail("Inputs must be smaller than prime");
+            }
+            if k == 0 || k > n {
+                fail("k must be between 1 and n");
+            }
+
+            // Each input is shared by its owner; from here on the parties only see shares
+            let a_shares = generate_shares(&a, n, k, &prime, &mut rng);
+            let b_shares = generate_shares(&b, n, k, &prime, &mut rng);
+            let triple = deal_triple(n, k, &prime, &mut rng);
+
+            println!("\n{} parties, threshold {}", n, k);
+            println!("\nShares of a and b");
+            for ((x, ya), (_, yb)) in a_shares.iter().zip(b_shares.iter()) {
+                println!("Party {} -> a: {}, b: {}", x, ya, yb);
+            }
+
+            let sum = add_shares(&a_shares, &b_shares, &prime).unwrap_or_else(|e| fail(&e));
+            let scaled = scale_shares(&a_shares, &c, &prime);
+            let product =
+                beaver_multiply(&a_shares, &b_shares, &triple, k, &prime).unwrap_or_else(|e| fail(&e));
+
+            println!("\nOpened during Beaver multiplication (masked, reveal nothing)");
+            println!("d = a - triple.a = {}", product.d);
+            println!("e = b - triple.b = {}", product.e);
+
+            let k_first = k as usize;
+            println!("\nResults, reconstructed from {} output shares", k);
+            println!("a + b = {}", reconstruct_secret(&sum[..k_first], &prime));
+            println!("{} * a = {}", c, reconstruct_secret(&scaled[..k_first], &prime));
+            println!("a * b = {} (Beaver triple)", reconstruct_secret(&product.shares[..k_first], &prime));
+
+            let local = multiply_local(&a_shares, &b_shares, &prime).unwrap_or_else(|e| fail(&e));
+            match reduce_degree(&local, k, &prime, &mut rng) {
+                Ok(reduced) => println!(
+                    "a * b = {} (local product + degree reduction)",
+                    reconstruct_secret(&reduced[..k_first], &prime)
+                ),
+                Err(e) => println!("Skipping local product + degree reduction: {}", e),
+            }
+        }
+
+        Commands::Regenerate { prime, shares, x, k } => {
+            let p = BigUint::from(prime);
+            let x = BigUint::from(x);
+            let tagged = parse_tagged_shares(&shares).unwrap_or_else(|e| fail(&e));
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+            let shares_vec = &generation.shares;
+
+            if &x % &p == BigUint::from(0u32) {
+                fail("x = 0 is the secret itself, not a share");
+            }
+            generation.check_k(k).unwrap_or_else(|e| fail(&e));
+
+            let poly = interpolate_threshold(shares_vec, k, &p).unwrap_or_else(|e| fail(&e));
+            let degree = poly.degree();
+            let y = poly.evaluate(&x, &p);
+
+            println!("\nInterpolated a polynomial of degree {} from {} shares", degree, shares_vec.len());
+            println!("\nRegenerated Share -> x: {}, y: {}", x, y);
+            println!("\nCLI Ready Format");
+            println!("{}", format_tagged_shares(generation.epoch, k, &[(x, y)]));
+        }
+
+        Commands::Refresh { prime, shares, k, distributed } => {
+            let p = BigUint::from(prime);
+            let tagged = parse_tagged_shares(&shares).unwrap_or_else(|e| fail(&e));
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+            let shares_vec = &generation.shares;
+
+            generation.check_k(k).unwrap_or_else(|e| fail(&e));
+            interpolate_threshold(shares_vec, k, &p).unwrap_or_else(|e| fail(&e));
+
+            let refreshed = if distributed {
+                refresh_shares_distributed(shares_vec, k, &p, &mut rng)
+            } else {
+                refresh_shares(shares_vec, k, &p, &mut rng)
+            }
+            .unwrap_or_else(|e| fail(&e));
+            let epoch = new_epoch(&mut rng);
+
+            if distributed {
+                println!("\nSimulated {} holders each adding its own zero-polynomial", shares_vec.len());
+            }
+            println!(
+                "\nRefreshed Shares (epoch {} -> {})",
+                format_epoch(generation.epoch),
+                format_epoch(epoch)
+            );
+            for (i, (x, y)) in refreshed.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, x, y);
+            }
+
+            println!("\nCLI Ready Format (delete the epoch {} shares)", format_epoch(generation.epoch));
+            println!("--shares \"{}\"", format_tagged_shares(epoch, k, &refreshed));
+        }
+
+        Commands::Reshare { prime, shares, old_k, n, k } => {
+            let p = BigUint::from(prime);
+            let tagged = parse_tagged_shares(&shares).unwrap_or_else(|e| fail(&e));
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+            let shares_vec = &generation.shares;
+
+            if k == 0 || k > n {
+                fail("New k must be between 1 and new n");
+            }
+            generation.check_k(old_k).unwrap_or_else(|e| fail(&e));
+            interpolate_threshold(shares_vec, old_k, &p).unwrap_or_else(|e| fail(&e));
+
+            let reshares = deal_reshares(shares_vec, n, k, &p, &mut rng);
+            println!("\nSimulated sub-shares dealt by the old holders");
+            for reshare in &reshares {
+                println!("Holder {} -> {}", reshare.dealer, format_shares(&reshare.sub_shares));
+            }
+
+            let new_shares = combine_reshares(shares_vec, &reshares, &p).unwrap_or_else(|e| fail(&e));
+            let epoch = new_epoch(&mut rng);
+            println!("\nNew Shares ({} of {}, epoch {})", k, n, format_epoch(epoch));
+            for (i, (x, y)) in new_shares.iter().enumerate() {
+                println!("Share {} -> x: {}, y: {}", i + 1, x, y);
+            }
+
+            println!("\nCLI Ready Format (delete the old shares)");
+            println!("--shares \"{}\"", format_tagged_shares(epoch, k, &new_shares));
+        }
+
+        Commands::ReshareDeal { prime, share, old_k, n, k, epoch } => {
+            let p = BigUint::from(prime);
+            let tagged = parse_tagged_shares(&share).unwrap_or_else(|e| fail(&e));
+            if tagged.len() != 1 {
+                fail("Provide exactly one share");
+            }
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+
+            if k == 0 || k > n {
+                fail("New k must be between 1 and new n");
+            }
+            if old_k == 0 {
+                fail("Old k must be at least 1");
+            }
+            generation.check_k(old_k).unwrap_or_else(|e| fail(&e));
+
+            let reshare = deal_reshare(&generation.shares[0], n, k, &p, &mut rng);
+
+            // Every old holder must tag its sub-shares with the same new epoch.
+            let epoch = match epoch {
+                Some(epoch) => parse_epoch(&epoch).unwrap_or_else(|e| fail(&e)),
+                None => {
+                    let epoch = new_epoch(&mut rng);
+                    println!("\nNew epoch {}; run the other old holders with --epoch {}", format_epoch(epoch), format_epoch(epoch));
+                    epoch
+                }
+            };
+            if epoch == 0 || epoch == generation.epoch {
+                fail("The new epoch must differ from 0 and from the epoch of the current shares");
+            }
+
+            println!("\nSub-shares of holder {} ({} of {}, epoch {})", reshare.dealer, k, n, format_epoch(epoch));
+            println!("Send each line privately to its new holder, then delete the old share once the new ones are in place");
+            for sub_share in &reshare.sub_shares {
+                println!(
+                    "New holder {} -> {}",
+                    sub_share.0,
+                    format_sub_share(&reshare.dealer, epoch, k, sub_share)
+                );
+            }
+        }
+
+        Commands::ReshareCombine { prime, sub_shares, old_k } => {
+            let p = BigUint::from(prime);
+            let received = parse_sub_shares(&sub_shares).unwrap_or_else(|e| fail(&e));
+            let (dealers, tagged): (Vec<BigUint>, Vec<_>) = received.into_iter().unzip();
+            let generation = same_generation(&tagged).unwrap_or_else(|e| fail(&e));
+
+            if old_k == 0 || dealers.len() < old_k as usize {
+                fail(&format!("Need sub-shares from at least old k = {} holders, got {}", old_k, dealers.len()));
+            }
+            let x = generation.shares[0].0.clone();
+            if generation.shares.iter().any(|(other, _)| *other != x) {
+                fail("Sub-shares are addressed to different new holders");
+            }
+            let Some(k) = generation.k else {
+                fail("Sub-shares must carry the new epoch and k, e.g. 1>g5d1c8e04a3b29f17/3:4,123");
+            };
+
+            let sub_values: Vec<BigUint> = generation.shares.iter().map(|(_, y)| y.clone()).collect();
+            let y = combine_sub_shares(&dealers, &sub_values, &p).unwrap_or_else(|e| fail(&e));
+
+            println!("\nCombined sub-shares from {} old holders", dealers.len());
+            println!("\nNew Share -> x: {}, y: {}", x, y);
+            println!("\nCLI Ready Format");
+            println!("{}", format_tagged_shares(generation.epoch, k, &[(x, y)]));
+        }
     }
 }
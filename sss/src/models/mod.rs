@@ -4,6 +4,11 @@ use clap::{Parser, Subcommand};
 pub struct Args {
     #[command(subcommand)]
     pub cmd: Commands,
+
+    /// UNSAFE FOR PRODUCTION: draw all randomness from ChaCha20 seeded with these
+    /// 32 bytes, so the same inputs give the same shares. Only for tests and vectors.
+    #[arg(long, global = true)]
+    pub seed_hex: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -22,6 +27,11 @@ pub enum Commands{
 
     #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
      p: u128,
+
+    /// Ramp scheme: fewer than this many shares reveal nothing, k still reconstruct,
+    /// and the secret is cut into k - ramp + 1 chunks so shares are that much smaller
+    #[arg(long)]
+    ramp: Option<u32>,
     },
     Reconstruct{
         #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
@@ -29,5 +39,246 @@ pub enum Commands{
 
     #[arg(short, long)]
     shares: String,
+
+    /// Shares come from `split --ramp` and look like "t/k:x,y"
+    #[arg(long)]
+    ramp: bool,
+    },
+
+    /// Weighted sharing: each participant gets as many points as their weight
+    SplitWeighted{
+        #[arg(short, long)]
+        secret: String,
+
+        /// Participants and weights, e.g. "ceo=2,alice=1,bob=1,carol=1"
+        #[arg(short = 'w', long)]
+        participants: String,
+
+        /// Total weight needed to reconstruct
+        #[arg(short, long)]
+        k: u32,
+
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        p: u128,
+    },
+    ReconstructWeighted{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// Weighted shares "name:k:x,y|x,y;name:k:x,y"
+        #[arg(short, long)]
+        shares: String,
+    },
+
+    /// Hierarchical (Tassa) sharing: level i needs k_i members from levels 0..=i
+    SplitHierarchical{
+        #[arg(short, long)]
+        secret: String,
+
+        /// Cumulative thresholds per level, most senior first, e.g. "1,3"
+        #[arg(short, long)]
+        levels: String,
+
+        /// Number of members at each level, e.g. "2,5"
+        #[arg(short, long)]
+        counts: String,
+
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        p: u128,
+    },
+    ReconstructHierarchical{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// Hierarchical shares "k0/k1:level:x,y;k0/k1:level:x,y"
+        #[arg(short, long)]
+        shares: String,
+    },
+    /// Tells whether members at the given levels could reconstruct
+    CheckHierarchical{
+        #[arg(short, long)]
+        levels: String,
+
+        /// Level of each member of the set, e.g. "0,1,1"
+        #[arg(short, long)]
+        members: String,
+    },
+
+    /// Policy (Benaloh–Leichter) sharing, e.g. "(Alice AND Bob) OR 2 of {Carol, Dave, Eve}"
+    SplitPolicy{
+        #[arg(short, long)]
+        secret: String,
+
+        #[arg(long)]
+        policy: String,
+
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        p: u128,
+    },
+    ReconstructPolicy{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// The policy the secret was split with
+        #[arg(long)]
+        policy: String,
+
+        /// Policy shares "name[path]=y;name[path]=y"
+        #[arg(short, long)]
+        shares: String,
+    },
+    /// Tells whether a set of parties satisfies a policy
+    CheckPolicy{
+        #[arg(long)]
+        policy: String,
+
+        /// Names of the parties, e.g. "Alice,Carol"
+        #[arg(short, long)]
+        members: String,
+    },
+
+    /// Packed sharing: a batch of l secrets in one polynomial of degree k + l - 1
+    SplitPacked{
+        /// Comma-separated batch, e.g. "1234,9876,5555"
+        #[arg(short, long)]
+        secrets: String,
+
+        /// Number of holders
+        #[arg(short, long)]
+        n: u32,
+
+        /// Any k shares reveal nothing; k + l shares recover the whole batch
+        #[arg(short, long)]
+        k: u32,
+
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        p: u128,
+    },
+    ReconstructPacked{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// Packed shares "l/k:x,y;l/k:x,y"
+        #[arg(short, long)]
+        shares: String,
+    },
+
+    /// Local MPC demo: computes a + b, c * a and a * b on shares, never reconstructing a or b
+    MpcDemo{
+        #[arg(short, long)]
+        a: u128,
+
+        #[arg(short, long)]
+        b: u128,
+
+        /// Public constant for the scalar multiplication
+        #[arg(short, long, default_value = "3")]
+        c: u128,
+
+        #[arg(short, long, default_value = "5")]
+        n: u32,
+
+        #[arg(short, long, default_value = "3")]
+        k: u32,
+
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        p: u128,
+    },
+
+    /// Recreates the share at x from k others of the same split
+    Regenerate{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        #[arg(short, long)]
+        shares: String,
+
+        /// x of the lost share, e.g. 7
+        #[arg(short, long)]
+        x: u128,
+
+        /// Threshold of the split; fewer shares are refused
+        #[arg(short, long)]
+        k: u32,
+    },
+
+    /// Proactive refresh: new shares of the same secret, tagged with a new random epoch
+    Refresh{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// Every holder's current share, "x,y;x,y" or "gE/k:x,y;gE/k:x,y"
+        #[arg(short, long)]
+        shares: String,
+
+        /// Threshold of the split; must match the k recorded in gE/k tags
+        #[arg(short, long)]
+        k: u32,
+
+        /// Simulates every holder contributing a zero-polynomial, all in this one process
+        #[arg(short, long)]
+        distributed: bool,
+    },
+
+    /// Simulates moving the secret to a new (n, k), running every old and new holder in this one process
+    Reshare{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// At least the old threshold of current shares, "x,y;x,y" or "gE/k:x,y;gE/k:x,y"
+        #[arg(short, long)]
+        shares: String,
+
+        /// Threshold the current shares were made for
+        #[arg(long)]
+        old_k: u32,
+
+        /// New number of holders
+        #[arg(short, long)]
+        n: u32,
+
+        /// New threshold
+        #[arg(short, long)]
+        k: u32,
+    },
+
+    /// Resharing step for one old holder: deals sub-shares of its own share to the new holders
+    ReshareDeal{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// This holder's share, "x,y" or "gE/k:x,y"
+        #[arg(short, long)]
+        share: String,
+
+        /// Threshold the current shares were made for
+        #[arg(long)]
+        old_k: u32,
+
+        /// New number of holders
+        #[arg(short, long)]
+        n: u32,
+
+        /// New threshold
+        #[arg(short, long)]
+        k: u32,
+
+        /// Epoch id for the new shares, the same on every old holder; drawn at random if omitted
+        #[arg(long)]
+        epoch: Option<String>,
+    },
+
+    /// Resharing step for one new holder: combines the sub-shares it received into its new share
+    ReshareCombine{
+        #[arg(short, long, default_value = "340282366920938463463374607431768211297")]
+        prime: u128,
+
+        /// Sub-shares from at least old-k old holders, "dealer>gE/k:x,y;dealer>gE/k:x,y"
+        #[arg(short, long)]
+        sub_shares: String,
+
+        /// Threshold the old shares were made for
+        #[arg(long)]
+        old_k: u32,
     }
 }
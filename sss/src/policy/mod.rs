@@ -0,0 +1,59 @@
+pub mod parser;
+
+use std::fmt;
+
+/// A monotone access policy. OR is kept as a 1-of-n threshold; AND is separate
+/// because it is shared additively instead of with Shamir.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Policy {
+    Party(String),
+    And(Vec<Policy>),
+    Threshold(u32, Vec<Policy>),
+}
+
+impl Policy {
+    /// Whether these parties together satisfy the policy.
+    pub fn is_satisfied(&self, names: &[String]) -> bool {
+        match self {
+            Policy::Party(name) => names.contains(name),
+            Policy::And(children) => children.iter().all(|child| child.is_satisfied(names)),
+            Policy::Threshold(k, children) => {
+                children.iter().filter(|child| child.is_satisfied(names)).count() as u32 >= *k
+            }
+        }
+    }
+
+    /// Every leaf with its path of child indices from the root.
+    pub fn leaves(&self) -> Vec<(String, Vec<usize>)> {
+        let mut leaves = Vec::new();
+        collect_leaves(self, &mut Vec::new(), &mut leaves);
+        leaves
+    }
+}
+
+fn collect_leaves(policy: &Policy, path: &mut Vec<usize>, leaves: &mut Vec<(String, Vec<usize>)>) {
+    match policy {
+        Policy::Party(name) => leaves.push((name.clone(), path.clone())),
+        Policy::And(children) | Policy::Threshold(_, children) => {
+            for (i, child) in children.iter().enumerate() {
+                path.push(i);
+                collect_leaves(child, path, leaves);
+                path.pop();
+            }
+        }
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |children: &[Policy], sep: &str| {
+            children.iter().map(|child| child.to_string()).collect::<Vec<String>>().join(sep)
+        };
+        match self {
+            Policy::Party(name) => write!(f, "{}", name),
+            Policy::And(children) => write!(f, "({})", join(children, " AND ")),
+            Policy::Threshold(1, children) => write!(f, "({})", join(children, " OR ")),
+            Policy::Threshold(k, children) => write!(f, "{} of ({})", k, join(children, ", ")),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::Policy;
+
+// Grammar (keywords are case-insensitive):
+//   expr   := term ("OR" term)*
+//   term   := factor ("AND" factor)*
+//   factor := NAME | "(" expr ")" | ["any"] NUM "of" ("(" | "{") expr ("," expr)* (")" | "}")
+// e.g. "(Alice AND Bob) OR any 2 of {Carol, Dave, Eve}"
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Comma,
+    Word(String),
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+
+    for ch in input.chars() {
+        let symbol = match ch {
+            '(' | '{' => Some(Token::Open),
+            ')' | '}' => Some(Token::Close),
+            ',' => Some(Token::Comma),
+            _ => None,
+        };
+        if symbol.is_some() || ch.is_whitespace() {
+            if !word.is_empty() {
+                tokens.push(Token::Word(std::mem::take(&mut word)));
+            }
+            tokens.extend(symbol);
+        } else {
+            word.push(ch);
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(Token::Word(word));
+    }
+    tokens
+}
+
+fn is_keyword(word: &str, keyword: &str) -> bool {
+    word.eq_ignore_ascii_case(keyword)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if is_keyword(word, keyword))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(format!("expected {}", what)),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Policy, String> {
+        let mut children = vec![self.term()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            children.push(self.term()?);
+        }
+        Ok(if children.len() == 1 { children.remove(0) } else { Policy::Threshold(1, children) })
+    }
+
+    fn term(&mut self) -> Result<Policy, String> {
+        let mut children = vec![self.factor()?];
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            children.push(self.factor()?);
+        }
+        Ok(if children.len() == 1 { children.remove(0) } else { Policy::And(children) })
+    }
+
+    fn factor(&mut self) -> Result<Policy, String> {
+        if self.peek_keyword("any") {
+            self.pos += 1;
+        }
+        match self.next() {
+            Some(Token::Open) => {
+                let inner = self.expr()?;
+                self.expect(Token::Close, "')'")?;
+                Ok(inner)
+            }
+            Some(Token::Word(word)) if word.chars().all(|c| c.is_ascii_digit()) && self.peek_keyword("of") => {
+                self.pos += 1;
+                let k: u32 = word.parse().map_err(|_| format!("threshold '{}' is too large", word))?;
+                self.expect(Token::Open, "'(' after 'of'")?;
+                let mut children = vec![self.expr()?];
+                while self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                    children.push(self.expr()?);
+                }
+                self.expect(Token::Close, "')' closing the threshold list")?;
+                if k == 0 || k as usize > children.len() {
+                    return Err(format!("'{} of' needs between 1 and {} members", k, children.len()));
+                }
+                Ok(Policy::Threshold(k, children))
+            }
+            Some(Token::Word(word)) => {
+                if ["and", "or", "of", "any"].iter().any(|keyword| is_keyword(&word, keyword)) {
+                    return Err(format!("unexpected keyword '{}'", word));
+                }
+                if word.contains(['[', ']', '=', ';']) {
+                    return Err(format!("name '{}' must not contain '[', ']', '=' or ';'", word));
+                }
+                Ok(Policy::Party(word))
+            }
+            Some(Token::Close) => Err("unexpected ')'".to_string()),
+            Some(Token::Comma) => Err("unexpected ','".to_string()),
+            None => Err("policy ended too early".to_string()),
+        }
+    }
+}
+
+pub fn parse_policy(input: &str) -> Result<Policy, String> {
+    let mut parser = Parser { tokens: tokenize(input), pos: 0 };
+    let policy = parser.expr()?;
+    if parser.pos < parser.tokens.len() {
+        return Err("unexpected text after the end of the policy".to_string());
+    }
+    Ok(policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn party(name: &str) -> Policy {
+        Policy::Party(name.to_string())
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        let policy = parse_policy("(Alice AND Bob) or any 2 of {Carol, Dave, Eve}").unwrap();
+        assert_eq!(
+            policy,
+            Policy::Threshold(1, vec![
+                Policy::And(vec![party("Alice"), party("Bob")]),
+                Policy::Threshold(2, vec![party("Carol"), party("Dave"), party("Eve")]),
+            ])
+        );
+        assert_eq!(parse_policy(&policy.to_string()), Ok(policy));
+    }
+
+    #[test]
+    fn test_policy_parser_errors() {
+        assert_eq!(parse_policy("Alice AND").unwrap_err(), "policy ended too early");
+        assert_eq!(parse_policy("(Alice OR Bob").unwrap_err(), "expected ')'");
+        assert_eq!(parse_policy("Alice Bob").unwrap_err(), "unexpected text after the end of the policy");
+        assert_eq!(parse_policy("3 of (Alice, Bob)").unwrap_err(), "'3 of' needs between 1 and 2 members");
+        assert_eq!(parse_policy("0 of (Alice)").unwrap_err(), "'0 of' needs between 1 and 1 members");
+        assert_eq!(parse_policy("Alice OR and").unwrap_err(), "unexpected keyword 'and'");
+        assert!(parse_policy("Alice=1").is_err());
+        assert!(parse_policy("99999999999 of (Alice)").is_err());
+        assert!(parse_policy(", Alice").is_err());
+    }
+}
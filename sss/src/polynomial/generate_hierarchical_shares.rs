@@ -0,0 +1,61 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::polynomial::Polynomial;
+
+/// A share in Tassa's hierarchical scheme.
+///
+/// `thresholds` are the cumulative level thresholds k_0 < k_1 < ... < k_m: a set is
+/// authorized when, for every level i, it has at least k_i members at levels 0..=i.
+/// A member of level i holds the value of the k_(i-1)-th derivative of f at x
+/// (level 0 holds f(x) itself), which is what makes lower levels unable to stand
+/// in for higher ones.
+pub struct HierarchicalShare {
+    pub thresholds: Vec<u32>,
+    pub level: usize,
+    pub x: BigUint,
+    pub y: BigUint,
+}
+
+impl HierarchicalShare {
+    pub fn derivative_order(&self) -> u32 {
+        derivative_order(&self.thresholds, self.level)
+    }
+}
+
+pub fn derivative_order(thresholds: &[u32], level: usize) -> u32 {
+    if level == 0 { 0 } else { thresholds[level - 1] }
+}
+
+/// Deals `counts[i]` shares to level i from one polynomial of degree k_m - 1.
+/// x-coordinates run 1, 2, ... starting with the most senior level, the order in
+/// which Tassa shows the Birkhoff system stays solvable.
+pub fn generate_hierarchical_shares<R: Rng + ?Sized>(
+    secret: &BigUint,
+    thresholds: &[u32],
+    counts: &[u32],
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<HierarchicalShare> {
+    let k = *thresholds.last().expect("at least one level");
+    let poly = Polynomial::new(secret, k - 1, prime, rng);
+
+    let mut next_x = 1u32;
+    let mut shares = Vec::new();
+
+    for (level, count) in counts.iter().enumerate() {
+        let derived = poly.derivative(derivative_order(thresholds, level), prime);
+        for _ in 0..*count {
+            let x = BigUint::from(next_x);
+            let y = derived.evaluate(&x, prime);
+            shares.push(HierarchicalShare {
+                thresholds: thresholds.to_vec(),
+                level,
+                x,
+                y,
+            });
+            next_x += 1;
+        }
+    }
+
+    shares
+}
@@ -0,0 +1,49 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+use crate::reconstruction::interpolate::interpolate;
+
+/// A share of a packed batch: one field element per holder, whatever l is.
+pub struct PackedShare {
+    pub l: u32,
+    pub k: u32,
+    pub x: BigUint,
+    pub y: BigUint,
+}
+
+/// Where secret j sits: x = -j (mod prime), clear of holders at 1..=n as long
+/// as n + k + l <= prime.
+pub fn secret_point(j: u32, prime: &BigUint) -> BigUint {
+    (prime - BigUint::from(j)) % prime
+}
+
+/// Packed Shamir: f has degree k + l - 1 with f(-j) = secrets[j] and k random
+/// values at -l..-(l + k - 1). Any k shares reveal nothing about the batch and
+/// any k + l recover all of it, while each holder still gets one element.
+pub fn generate_packed_shares<R: Rng + ?Sized>(
+    secrets: &[BigUint],
+    k: u32,
+    n: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<PackedShare> {
+    let l = secrets.len() as u32;
+
+    let mut points: Vec<(BigUint, BigUint)> = secrets
+        .iter()
+        .enumerate()
+        .map(|(j, secret)| (secret_point(j as u32, prime), secret.clone()))
+        .collect();
+    for j in l..l + k {
+        points.push((secret_point(j, prime), rng.gen_biguint_below(prime)));
+    }
+
+    let poly = interpolate(&points, prime).expect("fixed points are distinct");
+
+    (1..=n)
+        .map(|i| {
+            let x = BigUint::from(i);
+            let y = poly.evaluate(&x, prime);
+            PackedShare { l, k, x, y }
+        })
+        .collect()
+}
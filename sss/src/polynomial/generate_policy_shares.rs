@@ -0,0 +1,71 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+use crate::policy::Policy;
+use super::generate_shares::generate_shares;
+
+/// One sub-share for one occurrence of a party in the policy. `path` lists the
+/// child index taken at each gate from the root down to that leaf, so a party
+/// named twice gets two shares.
+pub struct PolicyShare {
+    pub party: String,
+    pub path: Vec<usize>,
+    pub value: BigUint,
+}
+
+/// Benaloh–Leichter: the root gets the secret and every gate re-shares the value
+/// it received among its children. A k-of-n gate (OR is 1-of-n) uses Shamir,
+/// child i getting the point at x = i + 1; an AND gate splits its value into
+/// random parts that add up to it.
+pub fn generate_policy_shares<R: Rng + ?Sized>(
+    secret: &BigUint,
+    policy: &Policy,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<PolicyShare> {
+    let mut shares = Vec::new();
+    share_node(secret, policy, &mut Vec::new(), prime, &mut shares, rng);
+    shares
+}
+
+fn share_node<R: Rng + ?Sized>(
+    value: &BigUint,
+    policy: &Policy,
+    path: &mut Vec<usize>,
+    prime: &BigUint,
+    shares: &mut Vec<PolicyShare>,
+    rng: &mut R,
+) {
+    let (children, child_values): (&[Policy], Vec<BigUint>) = match policy {
+        Policy::Party(name) => {
+            shares.push(PolicyShare {
+                party: name.clone(),
+                path: path.clone(),
+                value: value.clone(),
+            });
+            return;
+        }
+        Policy::And(children) => (children, additive_parts(value, children.len(), prime, rng)),
+        Policy::Threshold(k, children) => (
+            children,
+            generate_shares(value, children.len() as u32, *k, prime, rng)
+                .into_iter()
+                .map(|(_, y)| y)
+                .collect(),
+        ),
+    };
+
+    for (i, (child, child_value)) in children.iter().zip(child_values.iter()).enumerate() {
+        path.push(i);
+        share_node(child_value, child, path, prime, shares, rng);
+        path.pop();
+    }
+}
+
+/// `count` random values mod prime that add up to `value`.
+fn additive_parts<R: Rng + ?Sized>(value: &BigUint, count: usize, prime: &BigUint, rng: &mut R) -> Vec<BigUint> {
+    let mut parts: Vec<BigUint> = (1..count).map(|_| rng.gen_biguint_below(prime)).collect();
+
+    let used = parts.iter().fold(BigUint::from(0u32), |acc, part| (acc + part) % prime);
+    parts.push((value + prime - used) % prime);
+    parts
+}
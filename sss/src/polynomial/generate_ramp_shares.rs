@@ -0,0 +1,40 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::polynomial::Polynomial;
+
+/// A share of a ramp (t, k, n) scheme: k shares reconstruct, fewer than t
+/// reveal nothing about the secret.
+pub struct RampShare {
+    pub t: u32,
+    pub k: u32,
+    pub x: BigUint,
+    pub y: BigUint,
+}
+
+/// Number of secret chunks a (t, k) ramp polynomial carries.
+pub fn ramp_chunks(t: u32, k: u32) -> usize {
+    (k - t + 1) as usize
+}
+
+/// Puts the k - t + 1 chunks in the lowest coefficients of one polynomial of
+/// degree k - 1 and fills the other t - 1 with randomness. With t = k this is
+/// plain Shamir. Every share is still one field element, so it is 1/(k - t + 1)
+/// the size of the secret; the price is that t..k-1 shares leak part of it.
+pub fn generate_ramp_shares<R: Rng + ?Sized>(
+    chunks: &[BigUint],
+    t: u32,
+    k: u32,
+    n: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<RampShare> {
+    let poly = Polynomial::with_fixed(chunks, k - 1, prime, rng);
+
+    (1..=n)
+        .map(|i| {
+            let x = BigUint::from(i);
+            let y = poly.evaluate(&x, prime);
+            RampShare { t, k, x, y }
+        })
+        .collect()
+}
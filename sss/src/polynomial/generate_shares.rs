@@ -1,13 +1,15 @@
 use num_bigint::BigUint;
+use rand::Rng;
 use crate::polynomial::polynomial::Polynomial;
 
-pub fn generate_shares(
+pub fn generate_shares<R: Rng + ?Sized>(
     secret: &BigUint,
     n: u32,
     k: u32,
     prime: &BigUint,
+    rng: &mut R,
 ) -> Vec<(BigUint, BigUint)> {
-    let poly = Polynomial::new(secret, k - 1, prime);
+    let poly = Polynomial::new(secret, k - 1, prime, rng);
 
     let mut shares = Vec::new();
 
@@ -17,5 +19,5 @@ pub fn generate_shares(
         shares.push((x, y));
     }
 
-    return shares;
+    shares
 }
@@ -0,0 +1,82 @@
+use num_bigint::BigUint;
+use rand::Rng;
+use crate::polynomial::polynomial::Polynomial;
+
+/// One participant's points; the weight is the number of points.
+pub struct WeightedShare {
+    pub participant: String,
+    pub threshold: u32,
+    pub points: Vec<(BigUint, BigUint)>,
+}
+
+impl WeightedShare {
+    pub fn weight(&self) -> u32 {
+        self.points.len() as u32
+    }
+}
+
+/// The sum of all weights, or an error if it does not fit in a u32.
+pub fn total_weight(participants: &[(String, u32)]) -> Result<u32, String> {
+    participants
+        .iter()
+        .try_fold(0u32, |total, (_, weight)| total.checked_add(*weight))
+        .ok_or_else(|| format!("total weight must not exceed {}", u32::MAX))
+}
+
+/// Gives each participant `weight` consecutive evaluation points of one
+/// polynomial of degree k - 1, so any group whose weights add up to k can
+/// reconstruct. "CEO plus one director, or any three directors" is CEO = 2,
+/// directors = 1, k = 3.
+pub fn generate_weighted_shares<R: Rng + ?Sized>(
+    secret: &BigUint,
+    participants: &[(String, u32)],
+    k: u32,
+    prime: &BigUint,
+    rng: &mut R,
+) -> Vec<WeightedShare> {
+    let poly = Polynomial::new(secret, k - 1, prime, rng);
+
+    let mut next_x = 1u32;
+    let mut shares = Vec::new();
+
+    for (name, weight) in participants {
+        let mut points = Vec::new();
+        for _ in 0..*weight {
+            let x = BigUint::from(next_x);
+            let y = poly.evaluate(&x, prime);
+            points.push((x, y));
+            next_x += 1;
+        }
+        shares.push(WeightedShare {
+            participant: name.clone(),
+            threshold: k,
+            points,
+        });
+    }
+
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weights_give_consecutive_points() {
+        let prime = BigUint::from(7919u32);
+        let participants = vec![("ceo".to_string(), 2), ("alice".to_string(), 1)];
+        let shares = generate_weighted_shares(&BigUint::from(42u32), &participants, 3, &prime, &mut rand::thread_rng());
+
+        assert_eq!(shares[0].weight(), 2);
+        assert_eq!(shares[1].weight(), 1);
+        assert_eq!(shares[1].points[0].0, BigUint::from(3u32));
+        assert!(shares.iter().all(|share| share.threshold == 3));
+    }
+
+    #[test]
+    fn test_total_weight_overflow_is_an_error() {
+        let participants = vec![("a".to_string(), u32::MAX), ("b".to_string(), 1)];
+        assert!(total_weight(&participants).is_err());
+        assert_eq!(total_weight(&participants[1..]), Ok(1));
+    }
+}
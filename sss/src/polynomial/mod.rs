@@ -1,2 +1,8 @@
+#[allow(clippy::module_inception)]
 pub mod polynomial;
-pub mod generate_shares;
\ No newline at end of file
+pub mod generate_shares;
+pub mod generate_weighted_shares;
+pub mod generate_hierarchical_shares;
+pub mod generate_policy_shares;
+pub mod generate_ramp_shares;
+pub mod generate_packed_shares;
@@ -1,5 +1,6 @@
 use num_bigint::BigUint;
 use num_traits::Zero;
+use num_bigint::RandBigInt;
 use rand::Rng;
 
 pub struct Polynomial {
@@ -7,21 +8,38 @@ pub struct Polynomial {
 }
 
 impl Polynomial {
-    pub fn new(secret: &BigUint, degree: u32, prime: &BigUint) -> Self {
-        let mut coefficients = vec![secret.clone()];
-        let mut rng = rand::thread_rng();
+    pub fn new<R: Rng + ?Sized>(secret: &BigUint, degree: u32, prime: &BigUint, rng: &mut R) -> Self {
+        Self::with_fixed(std::slice::from_ref(secret), degree, prime, rng)
+    }
+
+    pub fn from_coefficients(coefficients: Vec<BigUint>) -> Self {
+        Polynomial { coefficients }
+    }
+
+    /// Polynomial whose lowest coefficients are `fixed` and whose remaining ones,
+    /// up to `degree`, are random non-zero values below the prime.
+    pub fn with_fixed<R: Rng + ?Sized>(fixed: &[BigUint], degree: u32, prime: &BigUint, rng: &mut R) -> Self {
+        let mut coefficients = fixed.to_vec();
         
-        for _ in 1..=degree {
-            let coeff = BigUint::from(rng.gen_range(1..prime.to_u32_digits()[0] as u128));
-            coefficients.push(coeff % prime);
+        for _ in fixed.len() as u32..=degree {
+            coefficients.push(rng.gen_biguint_range(&BigUint::from(1u32), prime));
         }
         
         Polynomial { coefficients }
     }
 
+    pub fn coefficients(&self) -> &[BigUint] {
+        &self.coefficients
+    }
+
+    /// Index of the highest non-zero coefficient; 0 for a constant.
+    pub fn degree(&self) -> usize {
+        self.coefficients.iter().rposition(|coeff| !coeff.is_zero()).unwrap_or(0)
+    }
+
     pub fn evaluate(&self, x: &BigUint, prime: &BigUint) -> BigUint {
         let mut result = BigUint::zero();
-        let mut x_power = BigUint::from(1 as u32);
+        let mut x_power = BigUint::from(1u32);
         
         for coeff in &self.coefficients {
             let term = (coeff * &x_power) % prime;
@@ -31,5 +49,24 @@ impl Polynomial {
         
         result
     }
-      
+
+    /// The `order`-th derivative: coefficient t becomes a_(t+order) * (t+order)! / t!.
+    pub fn derivative(&self, order: u32, prime: &BigUint) -> Polynomial {
+        let order = order as usize;
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(order)
+            .map(|(t, coeff)| {
+                let mut factor = BigUint::from(1u32);
+                for i in (t - order + 1)..=t {
+                    factor = (factor * BigUint::from(i)) % prime;
+                }
+                (coeff * factor) % prime
+            })
+            .collect();
+
+        Polynomial { coefficients }
+    }
 }
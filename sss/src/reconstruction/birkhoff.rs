@@ -0,0 +1,83 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Birkhoff interpolation: finds the k coefficients a_0..a_(k-1) of f from k
+/// values f^(d_j)(x_j), given as (x_j, d_j, y_j). Each value is one linear equation
+///   Σ_(t >= d_j) a_t * t!/(t-d_j)! * x_j^(t-d_j) = y_j  (mod prime),
+/// solved by Gaussian elimination. Returns None when the system is singular.
+/// With every d_j = 0 this is plain Lagrange interpolation.
+pub fn birkhoff_interpolate(
+    values: &[(BigUint, u32, BigUint)],
+    prime: &BigUint,
+) -> Option<Vec<BigUint>> {
+    let k = values.len();
+
+    let mut rows: Vec<Vec<BigUint>> = values
+        .iter()
+        .map(|(x, order, y)| {
+            let order = *order as usize;
+            let mut row = vec![BigUint::zero(); k + 1];
+            for (t, cell) in row.iter_mut().enumerate().take(k).skip(order) {
+                let mut factor = BigUint::from(1u32);
+                for i in (t - order + 1)..=t {
+                    factor = (factor * BigUint::from(i)) % prime;
+                }
+                *cell = (factor * x.modpow(&BigUint::from(t - order), prime)) % prime;
+            }
+            row[k] = y % prime;
+            row
+        })
+        .collect();
+
+    for col in 0..k {
+        let pivot = (col..k).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+
+        let inv = rows[col][col].modpow(&(prime - 2u32), prime);
+        for cell in rows[col].iter_mut() {
+            *cell = (&*cell * &inv) % prime;
+        }
+
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != col && !row[col].is_zero() {
+                let factor = row[col].clone();
+                for (cell, pivot_cell) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    let sub = (&factor * pivot_cell) % prime;
+                    *cell = (&*cell + prime - sub) % prime;
+                }
+            }
+        }
+    }
+
+    Some(rows.into_iter().map(|row| row[k].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(x: u32, order: u32, y: u32) -> (BigUint, u32, BigUint) {
+        (BigUint::from(x), order, BigUint::from(y))
+    }
+
+    #[test]
+    fn test_birkhoff_solves_mixed_derivatives() {
+        // f(x) = 5 + 3x + 2x^2, f'(x) = 3 + 4x
+        let prime = BigUint::from(97u32);
+        let values = vec![value(1, 0, 10), value(2, 1, 11), value(3, 1, 15)];
+
+        let coefficients = birkhoff_interpolate(&values, &prime).unwrap();
+        assert_eq!(coefficients, vec![BigUint::from(5u32), BigUint::from(3u32), BigUint::from(2u32)]);
+    }
+
+    #[test]
+    fn test_singular_birkhoff_system() {
+        let prime = BigUint::from(97u32);
+
+        // Only derivatives: nothing pins down a_0.
+        assert!(birkhoff_interpolate(&[value(1, 1, 7), value(2, 1, 11)], &prime).is_none());
+        // The same point twice.
+        assert!(birkhoff_interpolate(&[value(1, 0, 10), value(1, 0, 10)], &prime).is_none());
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use crate::polynomial::polynomial::Polynomial;
+
+/// Lagrange interpolation of the whole polynomial: the unique f of degree
+/// below shares.len() through every share, as coefficients. None if two shares
+/// have the same x.
+pub fn interpolate(shares: &[(BigUint, BigUint)], prime: &BigUint) -> Option<Polynomial> {
+    let mut seen = HashSet::new();
+    if !shares.iter().all(|(x, _)| seen.insert(x % prime)) {
+        return None;
+    }
+
+    let mut coefficients = vec![BigUint::from(0u32); shares.len()];
+
+    for (j, (xj, yj)) in shares.iter().enumerate() {
+        // basis = ∏_(m != j) (x - x_m), built up one factor at a time
+        let mut basis = vec![BigUint::from(1u32)];
+        let mut den = BigUint::from(1u32);
+        for (m, (xm, _)) in shares.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+            let neg_xm = (prime - xm % prime) % prime;
+            let mut next = vec![BigUint::from(0u32); basis.len() + 1];
+            for (i, coeff) in basis.iter().enumerate() {
+                next[i + 1] = (&next[i + 1] + coeff) % prime;
+                next[i] = (&next[i] + coeff * &neg_xm) % prime;
+            }
+            basis = next;
+            den = (den * ((xj + &neg_xm) % prime)) % prime;
+        }
+
+        let scale = (yj * den.modpow(&(prime - 2u32), prime)) % prime;
+        for (total, coeff) in coefficients.iter_mut().zip(basis.iter()) {
+            *total = (&*total + coeff * &scale) % prime;
+        }
+    }
+
+    Some(Polynomial::from_coefficients(coefficients))
+}
+
+/// Interpolates shares of a threshold-k split. Fails on fewer than k shares,
+/// and on more than k that do not lie on one polynomial of degree below k,
+/// which means a wrong k, a corrupt share or shares from different splits.
+pub fn interpolate_threshold(shares: &[(BigUint, BigUint)], k: u32, prime: &BigUint) -> Result<Polynomial, String> {
+    if k == 0 {
+        return Err("k must be at least 1".to_string());
+    }
+    if shares.len() < k as usize {
+        return Err(format!("need at least k = {} shares, got {}", k, shares.len()));
+    }
+    let poly = interpolate(shares, prime).ok_or("shares have repeated x values")?;
+    if poly.degree() >= k as usize {
+        return Err(format!(
+            "the {} shares lie on a polynomial of degree {}, not below k = {}; check k and drop corrupt or foreign shares",
+            shares.len(),
+            poly.degree(),
+            k
+        ));
+    }
+    Ok(poly)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(values: &[(u32, u32)]) -> Vec<(BigUint, BigUint)> {
+        values.iter().map(|&(x, y)| (BigUint::from(x), BigUint::from(y))).collect()
+    }
+
+    #[test]
+    fn test_interpolate_recovers_coefficients() {
+        // f(x) = 7 + 2x + 3x^2 mod 97
+        let prime = BigUint::from(97u32);
+        let poly = interpolate(&points(&[(1, 12), (2, 23), (3, 40)]), &prime).unwrap();
+
+        assert_eq!(poly.coefficients(), &[BigUint::from(7u32), BigUint::from(2u32), BigUint::from(3u32)]);
+        assert_eq!(poly.evaluate(&BigUint::from(5u32), &prime), BigUint::from(92u32));
+        assert!(interpolate(&points(&[(1, 12), (98, 23)]), &prime).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_threshold_checks_the_degree() {
+        let prime = BigUint::from(97u32);
+        let on_curve = points(&[(1, 12), (2, 23), (3, 40), (4, 63)]);
+
+        assert_eq!(interpolate_threshold(&on_curve, 3, &prime).unwrap().degree(), 2);
+        assert_eq!(
+            interpolate_threshold(&on_curve[..2], 3, &prime).err().as_deref(),
+            Some("need at least k = 3 shares, got 2")
+        );
+        assert!(interpolate_threshold(&on_curve, 0, &prime).is_err());
+        // With k = 2 the four shares cannot lie on a line.
+        assert!(interpolate_threshold(&on_curve, 2, &prime).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_threshold_rejects_a_foreign_share() {
+        let prime = BigUint::from(97u32);
+        let mixed = points(&[(1, 12), (2, 23), (3, 40), (4, 64)]);
+
+        assert!(interpolate_threshold(&mixed, 3, &prime).is_err());
+        assert!(interpolate_threshold(&points(&[(1, 12), (1, 12), (2, 23)]), 3, &prime).is_err());
+    }
+}
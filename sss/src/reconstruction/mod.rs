@@ -1,2 +1,9 @@
 pub mod lagrange;
+pub mod interpolate;
+pub mod birkhoff;
 pub mod reconstruct_secret;
+pub mod reconstruct_weighted;
+pub mod reconstruct_hierarchical;
+pub mod reconstruct_policy;
+pub mod reconstruct_ramp;
+pub mod reconstruct_packed;
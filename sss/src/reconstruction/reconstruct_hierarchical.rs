@@ -0,0 +1,103 @@
+use num_bigint::BigUint;
+use crate::polynomial::generate_hierarchical_shares::HierarchicalShare;
+use super::birkhoff::birkhoff_interpolate;
+
+/// Checks Tassa's condition: for every level i the set has at least k_i members
+/// at levels 0..=i. Returns the first level that falls short.
+pub fn is_authorized(thresholds: &[u32], levels: &[usize]) -> Result<(), String> {
+    for (i, needed) in thresholds.iter().enumerate() {
+        let have = levels.iter().filter(|&&level| level <= i).count() as u32;
+        if have < *needed {
+            return Err(format!(
+                "levels 0..={} have {} members but need at least {}",
+                i, have, needed
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks the set is authorized, keeps the k most senior shares (which are still
+/// authorized) and solves for f(0) with Birkhoff interpolation.
+pub fn reconstruct_hierarchical(shares: &[HierarchicalShare], prime: &BigUint) -> Result<BigUint, String> {
+    let thresholds = match shares.first() {
+        Some(share) => share.thresholds.clone(),
+        None => return Err("no shares given".to_string()),
+    };
+    if shares.iter().any(|share| share.thresholds != thresholds) {
+        return Err("shares come from splits with different levels".to_string());
+    }
+    if shares.iter().any(|share| share.level >= thresholds.len()) {
+        return Err("a share names a level that does not exist".to_string());
+    }
+
+    let levels: Vec<usize> = shares.iter().map(|share| share.level).collect();
+    is_authorized(&thresholds, &levels)?;
+
+    let mut sorted: Vec<&HierarchicalShare> = shares.iter().collect();
+    sorted.sort_by_key(|share| share.level);
+    let k = *thresholds.last().expect("checked above") as usize;
+
+    let values: Vec<(BigUint, u32, BigUint)> = sorted
+        .iter()
+        .take(k)
+        .map(|share| (share.x.clone(), share.derivative_order(), share.y.clone()))
+        .collect();
+
+    match birkhoff_interpolate(&values, prime) {
+        Some(coefficients) => Ok(coefficients[0].clone()),
+        None => Err("these shares give a singular Birkhoff system (repeated x?)".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_hierarchical_shares::generate_hierarchical_shares;
+
+    // One senior (level 0) and three juniors (level 1); any 3 members including
+    // the senior are authorized.
+    fn split(secret: u32) -> Vec<HierarchicalShare> {
+        generate_hierarchical_shares(&BigUint::from(secret), &[1, 3], &[1, 3], &BigUint::from(7919u32), &mut rand::thread_rng())
+    }
+
+    #[test]
+    fn test_authorized_set_reconstructs() {
+        let prime = BigUint::from(7919u32);
+        let shares = split(4321);
+
+        assert_eq!(reconstruct_hierarchical(&shares[..3], &prime), Ok(BigUint::from(4321u32)));
+        assert_eq!(reconstruct_hierarchical(&shares, &prime), Ok(BigUint::from(4321u32)));
+    }
+
+    #[test]
+    fn test_unauthorized_set_is_refused() {
+        let prime = BigUint::from(7919u32);
+        let shares = split(4321);
+
+        // Three juniors are enough people but no senior.
+        assert_eq!(
+            reconstruct_hierarchical(&shares[1..], &prime).unwrap_err(),
+            "levels 0..=0 have 0 members but need at least 1"
+        );
+        assert!(reconstruct_hierarchical(&shares[..2], &prime).is_err());
+        assert!(reconstruct_hierarchical(&[], &prime).is_err());
+    }
+
+    #[test]
+    fn test_is_authorized() {
+        assert!(is_authorized(&[1, 3], &[0, 1, 1]).is_ok());
+        assert!(is_authorized(&[1, 3], &[0, 0, 0]).is_ok());
+        assert!(is_authorized(&[1, 3], &[1, 1, 1]).is_err());
+        assert!(is_authorized(&[2, 3], &[0, 1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_mixed_splits_are_refused() {
+        let prime = BigUint::from(7919u32);
+        let mut shares = split(4321);
+        shares[1].thresholds = vec![1, 2];
+
+        assert!(reconstruct_hierarchical(&shares[..3], &prime).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use crate::polynomial::generate_packed_shares::{PackedShare, secret_point};
+use super::interpolate::interpolate;
+
+/// Interpolates f once from k + l shares and reads every secret of the batch
+/// off it at its fixed point.
+pub fn reconstruct_packed(shares: &[PackedShare], prime: &BigUint) -> Result<Vec<BigUint>, String> {
+    let (l, k) = match shares.first() {
+        Some(share) => (share.l, share.k),
+        None => return Err("no shares given".to_string()),
+    };
+    if shares.iter().any(|share| share.l != l || share.k != k) {
+        return Err("shares come from different batches".to_string());
+    }
+
+    let needed = k.checked_add(l).ok_or("k + l is too large")? as usize;
+    let mut seen = HashSet::new();
+    let points: Vec<(BigUint, BigUint)> = shares
+        .iter()
+        .filter(|share| seen.insert(share.x.clone()))
+        .take(needed)
+        .map(|share| (share.x.clone(), share.y.clone()))
+        .collect();
+    if points.len() < needed {
+        return Err(format!("need {} distinct shares, got {}", needed, points.len()));
+    }
+
+    let poly = interpolate(&points, prime).ok_or("shares have repeated x values")?;
+    Ok((0..l).map(|j| poly.evaluate(&secret_point(j, prime), prime)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_packed_shares::generate_packed_shares;
+
+    #[test]
+    fn test_k_plus_l_shares_recover_the_batch() {
+        let prime = BigUint::from(7919u32);
+        let secrets = vec![BigUint::from(1234u32), BigUint::from(0u32), BigUint::from(7000u32)];
+        let shares = generate_packed_shares(&secrets, 2, 6, &prime, &mut rand::thread_rng());
+
+        assert_eq!(reconstruct_packed(&shares[1..], &prime), Ok(secrets.clone()));
+        assert_eq!(reconstruct_packed(&shares[..4], &prime).unwrap_err(), "need 5 distinct shares, got 4");
+    }
+
+    #[test]
+    fn test_mixing_batches_is_refused() {
+        let prime = BigUint::from(7919u32);
+        let mut first = generate_packed_shares(&[BigUint::from(1u32)], 2, 4, &prime, &mut rand::thread_rng());
+        let second = generate_packed_shares(&[BigUint::from(1u32), BigUint::from(2u32)], 1, 4, &prime, &mut rand::thread_rng());
+        first.extend(second);
+
+        assert_eq!(reconstruct_packed(&first, &prime).unwrap_err(), "shares come from different batches");
+    }
+
+    #[test]
+    fn test_k_plus_l_overflow_is_an_error() {
+        let prime = BigUint::from(7919u32);
+        let share = PackedShare { l: 1, k: u32::MAX, x: BigUint::from(1u32), y: BigUint::from(2u32) };
+
+        assert_eq!(reconstruct_packed(&[share], &prime).unwrap_err(), "k + l is too large");
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use num_bigint::BigUint;
+use crate::helper::share_format::format_path;
+use crate::policy::Policy;
+use crate::polynomial::generate_policy_shares::PolicyShare;
+use super::reconstruct_secret::reconstruct_secret;
+
+/// Walks the policy bottom-up: a leaf is known if its share was given, an AND
+/// gate if all children are known, a k-of-n gate if k children are. Returns
+/// Ok(None) when the shares do not satisfy the policy.
+pub fn reconstruct_policy(
+    shares: &[PolicyShare],
+    policy: &Policy,
+    prime: &BigUint,
+) -> Result<Option<BigUint>, String> {
+    let leaves = policy.leaves();
+    let mut known = HashMap::new();
+
+    for share in shares {
+        if !leaves.iter().any(|(party, path)| *party == share.party && *path == share.path) {
+            return Err(format!(
+                "share for '{}' at [{}] does not belong to this policy",
+                share.party,
+                format_path(&share.path)
+            ));
+        }
+        if known.insert((share.party.as_str(), share.path.clone()), &share.value).is_some() {
+            return Err(format!("share for '{}' at [{}] is given twice", share.party, format_path(&share.path)));
+        }
+    }
+
+    Ok(recover_node(policy, &mut Vec::new(), &known, prime))
+}
+
+fn recover_node(
+    policy: &Policy,
+    path: &mut Vec<usize>,
+    known: &HashMap<(&str, Vec<usize>), &BigUint>,
+    prime: &BigUint,
+) -> Option<BigUint> {
+    let mut recover_child = |i: usize, child: &Policy| {
+        path.push(i);
+        let value = recover_node(child, path, known, prime);
+        path.pop();
+        value
+    };
+
+    match policy {
+        Policy::Party(name) => known.get(&(name.as_str(), path.clone())).map(|&value| value.clone()),
+        Policy::And(children) => {
+            let mut sum = BigUint::from(0u32);
+            for (i, child) in children.iter().enumerate() {
+                sum = (sum + recover_child(i, child)?) % prime;
+            }
+            Some(sum)
+        }
+        Policy::Threshold(k, children) => {
+            let points: Vec<(BigUint, BigUint)> = children
+                .iter()
+                .enumerate()
+                .filter_map(|(i, child)| recover_child(i, child).map(|y| (BigUint::from(i + 1), y)))
+                .take(*k as usize)
+                .collect();
+            if points.len() < *k as usize {
+                return None;
+            }
+            Some(reconstruct_secret(&points, prime))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::parser::parse_policy;
+    use crate::polynomial::generate_policy_shares::generate_policy_shares;
+
+    fn shares_of(shares: &[PolicyShare], names: &[&str]) -> Vec<PolicyShare> {
+        shares
+            .iter()
+            .filter(|share| names.contains(&share.party.as_str()))
+            .map(|share| PolicyShare { party: share.party.clone(), path: share.path.clone(), value: share.value.clone() })
+            .collect()
+    }
+
+    #[test]
+    fn test_satisfied_policy_reconstructs() {
+        let prime = BigUint::from(7919u32);
+        let policy = parse_policy("(Alice AND Bob) OR 2 of (Carol, Dave, Eve)").unwrap();
+        let shares = generate_policy_shares(&BigUint::from(777u32), &policy, &prime, &mut rand::thread_rng());
+
+        for names in [&["Alice", "Bob"][..], &["Carol", "Eve"], &["Alice", "Dave", "Eve"]] {
+            let given = shares_of(&shares, names);
+            assert_eq!(reconstruct_policy(&given, &policy, &prime), Ok(Some(BigUint::from(777u32))));
+        }
+    }
+
+    #[test]
+    fn test_unsatisfied_policy() {
+        let prime = BigUint::from(7919u32);
+        let policy = parse_policy("(Alice AND Bob) OR 2 of (Carol, Dave, Eve)").unwrap();
+        let shares = generate_policy_shares(&BigUint::from(777u32), &policy, &prime, &mut rand::thread_rng());
+
+        for names in [&["Alice", "Carol"][..], &["Bob"], &[]] {
+            let given = shares_of(&shares, names);
+            assert_eq!(reconstruct_policy(&given, &policy, &prime), Ok(None));
+        }
+    }
+
+    #[test]
+    fn test_foreign_or_repeated_shares_are_refused() {
+        let prime = BigUint::from(7919u32);
+        let policy = parse_policy("Alice AND Bob").unwrap();
+        let shares = generate_policy_shares(&BigUint::from(777u32), &policy, &prime, &mut rand::thread_rng());
+
+        let mut repeated = shares_of(&shares, &["Alice", "Bob"]);
+        repeated.extend(shares_of(&shares, &["Alice"]));
+        assert!(reconstruct_policy(&repeated, &policy, &prime).is_err());
+
+        let foreign = vec![PolicyShare { party: "Mallory".to_string(), path: vec![0], value: BigUint::from(1u32) }];
+        assert!(reconstruct_policy(&foreign, &policy, &prime).is_err());
+    }
+}
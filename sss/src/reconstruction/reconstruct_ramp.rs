@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use crate::polynomial::generate_ramp_shares::{RampShare, ramp_chunks};
+use super::interpolate::interpolate;
+
+/// Recovers the whole polynomial from k shares and returns its chunk coefficients.
+pub fn reconstruct_ramp(shares: &[RampShare], prime: &BigUint) -> Result<Vec<BigUint>, String> {
+    let (t, k) = match shares.first() {
+        Some(share) => (share.t, share.k),
+        None => return Err("no shares given".to_string()),
+    };
+    if shares.iter().any(|share| share.t != t || share.k != k) {
+        return Err("shares come from splits with different ramp parameters".to_string());
+    }
+
+    let mut seen = HashSet::new();
+    let points: Vec<(BigUint, BigUint)> = shares
+        .iter()
+        .filter(|share| seen.insert(share.x.clone()))
+        .take(k as usize)
+        .map(|share| (share.x.clone(), share.y.clone()))
+        .collect();
+    if points.len() < k as usize {
+        return Err(format!("need {} distinct shares, got {}", k, points.len()));
+    }
+
+    let poly = interpolate(&points, prime).ok_or("shares have repeated x values")?;
+    Ok(poly.coefficients()[..ramp_chunks(t, k)].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_ramp_shares::generate_ramp_shares;
+
+    #[test]
+    fn test_k_shares_give_every_chunk() {
+        let prime = BigUint::from(7919u32);
+        let chunks = vec![BigUint::from(11u32), BigUint::from(0u32), BigUint::from(33u32)];
+        let shares = generate_ramp_shares(&chunks, 2, 4, 5, &prime, &mut rand::thread_rng());
+
+        assert_eq!(reconstruct_ramp(&shares[1..], &prime), Ok(chunks.clone()));
+        assert_eq!(reconstruct_ramp(&shares[..3], &prime).unwrap_err(), "need 4 distinct shares, got 3");
+    }
+
+    #[test]
+    fn test_mixed_ramp_parameters_are_refused() {
+        let prime = BigUint::from(7919u32);
+        let chunks = vec![BigUint::from(11u32), BigUint::from(22u32)];
+        let mut shares = generate_ramp_shares(&chunks, 2, 3, 4, &prime, &mut rand::thread_rng());
+        shares[0].t = 3;
+
+        assert!(reconstruct_ramp(&shares, &prime).is_err());
+    }
+}
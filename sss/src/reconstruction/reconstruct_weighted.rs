@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use crate::polynomial::generate_weighted_shares::WeightedShare;
+use super::reconstruct_secret::reconstruct_secret;
+
+/// Pools the points of all given participants and reconstructs once their
+/// combined weight reaches the threshold recorded in the shares.
+pub fn reconstruct_weighted(shares: &[WeightedShare], prime: &BigUint) -> Result<BigUint, String> {
+    let threshold = match shares.first() {
+        Some(share) => share.threshold,
+        None => return Err("no shares given".to_string()),
+    };
+    if shares.iter().any(|share| share.threshold != threshold) {
+        return Err("shares come from splits with different thresholds".to_string());
+    }
+
+    let mut names = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut points = Vec::new();
+    for share in shares {
+        if !names.insert(&share.participant) {
+            return Err(format!("participant '{}' is given twice", share.participant));
+        }
+        for (x, y) in &share.points {
+            if seen.insert(x.clone()) {
+                points.push((x.clone(), y.clone()));
+            }
+        }
+    }
+
+    let total = points.len() as u32;
+    if total < threshold {
+        return Err(format!("total weight {} is below the threshold {}", total, threshold));
+    }
+
+    points.truncate(threshold as usize);
+    Ok(reconstruct_secret(&points, prime))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::generate_weighted_shares::generate_weighted_shares;
+
+    fn split(secret: u32) -> Vec<WeightedShare> {
+        let participants = vec![
+            ("ceo".to_string(), 2),
+            ("alice".to_string(), 1),
+            ("bob".to_string(), 1),
+        ];
+        generate_weighted_shares(&BigUint::from(secret), &participants, 3, &BigUint::from(7919u32), &mut rand::thread_rng())
+    }
+
+    #[test]
+    fn test_enough_weight_reconstructs() {
+        let prime = BigUint::from(7919u32);
+        let shares = split(1234);
+
+        assert_eq!(reconstruct_weighted(&shares[..2], &prime), Ok(BigUint::from(1234u32)));
+        assert_eq!(reconstruct_weighted(&shares, &prime), Ok(BigUint::from(1234u32)));
+    }
+
+    #[test]
+    fn test_weight_below_k_fails() {
+        let prime = BigUint::from(7919u32);
+        let shares = split(1234);
+
+        assert_eq!(reconstruct_weighted(&shares[1..], &prime).unwrap_err(), "total weight 2 is below the threshold 3");
+        assert!(reconstruct_weighted(&shares[..1], &prime).is_err());
+        assert!(reconstruct_weighted(&[], &prime).is_err());
+    }
+
+    #[test]
+    fn test_participant_given_twice_is_refused() {
+        let prime = BigUint::from(7919u32);
+        let mut shares = split(1234);
+        let ceo = WeightedShare {
+            participant: "ceo".to_string(),
+            threshold: 3,
+            points: shares[0].points.clone(),
+        };
+        shares.push(ceo);
+
+        assert_eq!(reconstruct_weighted(&shares, &prime).unwrap_err(), "participant 'ceo' is given twice");
+    }
+}
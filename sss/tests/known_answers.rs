@@ -0,0 +1,197 @@
+//! Known-answer vectors. Each split runs with a fixed `--seed-hex`, so the
+//! shares must match exactly; if one changes, the randomness or the share
+//! layout changed and other implementations comparing against these vectors
+//! will break. Every vector is also reconstructed back to its secret.
+
+use std::process::Command;
+
+const SEED: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+fn sss(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_sss"))
+        .args(args)
+        .output()
+        .expect("failed to run sss");
+    assert!(output.status.success(), "sss {:?} failed: {}", args, String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The value of the `--shares "..."` line a split prints.
+fn cli_shares(stdout: &str) -> String {
+    let line = stdout.lines().find(|line| line.starts_with("--shares")).expect("no --shares line");
+    line.trim_start_matches("--shares \"").trim_end_matches('"').to_string()
+}
+
+/// The lines after "CLI Ready Format", for splits that print one share per line.
+fn cli_lines(stdout: &str) -> String {
+    let lines: Vec<&str> = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("CLI Ready Format"))
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    lines.join(";")
+}
+
+fn reconstructed(stdout: &str) -> String {
+    let line = stdout.lines().find(|line| line.starts_with("The Reconstructed String is : ")).expect("no secret");
+    line.trim_start_matches("The Reconstructed String is : ").to_string()
+}
+
+#[test]
+fn test_split_vector() {
+    let expected = "1,291681323697066651986166888299408364601;\
+                    2,291315860136872920936476336457477534546;\
+                    3,339185976240357270314302951905982758432;\
+                    4,95009305086581236656272127213155824962;\
+                    5,239350580517421746889133077242533156730";
+    let shares = cli_shares(&sss(&["split", "-s", "kat", "-n", "5", "-k", "3", "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let some: Vec<&str> = expected.split(';').skip(2).collect();
+    assert_eq!(reconstructed(&sss(&["reconstruct", "-s", &some.join(";")])), "kat");
+}
+
+#[test]
+fn test_weighted_vector() {
+    let expected = "ceo:3:1,291681323697066651986166888722176779561|2,291315860136872920936476336880245949506;\
+                    alice:3:3,339185976240357270314302952328751173392;\
+                    bob:3:4,95009305086581236656272127635924239922";
+    let shares = cli_lines(&sss(&["split-weighted", "-s", "board", "-w", "ceo=2,alice=1,bob=1", "-k", "3", "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let ceo_bob: Vec<&str> = expected.split(';').filter(|share| !share.starts_with("alice")).collect();
+    assert_eq!(reconstructed(&sss(&["reconstruct-weighted", "-s", &ceo_bob.join(";")])), "board");
+}
+
+#[test]
+fn test_hierarchical_vector() {
+    let expected = "1/3:0:1,291681323697066651986166888799385789752;\
+                    1/3:0:2,291315860136872920936476336957454959697;\
+                    1/3:1:3,242129089395792621323272502809607356505;\
+                    1/3:1:4,290364669059470701750789670100043410446;\
+                    1/3:1:5,338600248723148782178306837390479464387";
+    let shares = cli_shares(&sss(&["split-hierarchical", "-s", "tiers", "-l", "1,3", "-c", "2,3", "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    // One level-0 member and two level-1 members meet both thresholds.
+    let shares: Vec<&str> = expected.split(';').collect();
+    let senior_and_two = [shares[0], shares[2], shares[4]];
+    assert_eq!(reconstructed(&sss(&["reconstruct-hierarchical", "-s", &senior_and_two.join(";")])), "tiers");
+}
+
+#[test]
+fn test_ramp_vector() {
+    let expected = "2/3:1,98610227880300238090146684863975187101;\
+                    2/3:2,51188891743002968346113445675003435754;\
+                    2/3:3,198612256415222458683522208362630073771;\
+                    2/3:4,200597954976020245638998365495086889855";
+    let secret = "ramp vector secret, 2 chunks";
+    let shares = cli_shares(&sss(&["split", "-s", secret, "-n", "4", "-k", "3", "--ramp", "2", "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    assert_eq!(reconstructed(&sss(&["reconstruct", "--ramp", "-s", expected])), secret);
+}
+
+#[test]
+fn test_packed_vector() {
+    let expected = "2/1:1,97422350404758380040721000937895358771;\
+                    2/1:2,292267051214275140122163002812169941299;\
+                    2/1:3,244251735507611816780951398191880909779;\
+                    2/1:4,293658770205706873480460794508796475508";
+    let shares = cli_shares(&sss(&["split-packed", "-s", "1234,9876", "-k", "1", "-n", "4", "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let stdout = sss(&["reconstruct-packed", "-s", expected]);
+    assert!(stdout.contains("Secret 1 : 1234") && stdout.contains("Secret 2 : 9876"));
+}
+
+#[test]
+fn test_policy_vector() {
+    let policy = "(Alice AND Bob) OR 2 of (Carol, Dave, Eve)";
+    let expected = "Alice[0.0]=97422350404758380040721000938299194681;\
+                    Bob[0.1]=242860016516180083422653730117331543457;\
+                    Carol[1.0]=194258973292308271945446010984964659460;\
+                    Dave[1.1]=48235579663678080427517290914298580782;\
+                    Eve[1.2]=242494552955986352372963178275400713401";
+    let shares = cli_shares(&sss(&["split-policy", "-s", "policy", "--policy", policy, "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let carol_eve: Vec<&str> = expected.split(';').filter(|share| share.starts_with("Carol") || share.starts_with("Eve")).collect();
+    assert_eq!(reconstructed(&sss(&["reconstruct-policy", "--policy", policy, "-s", &carol_eve.join(";")])), "policy");
+}
+
+#[test]
+fn test_regenerate_vector() {
+    // Regenerating needs no randomness: share 7 of the split vector, from either end.
+    let split = "1,291681323697066651986166888299408364601;\
+                 2,291315860136872920936476336457477534546;\
+                 3,339185976240357270314302951905982758432;\
+                 4,95009305086581236656272127213155824962;\
+                 5,239350580517421746889133077242533156730";
+    let expected = "7,332457503449198545174031871740827770792";
+    let shares: Vec<&str> = split.split(';').collect();
+
+    for some in [&shares[..3], &shares[2..]] {
+        let stdout = sss(&["regenerate", "-k", "3", "-x", "7", "-s", &some.join(";")]);
+        assert_eq!(stdout.lines().last(), Some(expected));
+    }
+}
+
+#[test]
+fn test_refresh_vector() {
+    // Shares 1..3 of the k = 3 split vector, which hold "kat".
+    let split = "1,291681323697066651986166888299408364601;\
+                 2,291315860136872920936476336457477534546;\
+                 3,339185976240357270314302951905982758432";
+    let expected = "gab2360a2e7cc232b/3:1,243080280473194840508959169167041480605;\
+                    gab2360a2e7cc232b/3:2,242349353352807378409578065483179820495;\
+                    gab2360a2e7cc232b/3:3,338089585559776077165231296380190268267";
+    let shares = cli_shares(&sss(&["refresh", "-k", "3", "-s", split, "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let stdout = sss(&["reconstruct", "-s", expected]);
+    assert!(stdout.contains("(epoch ab2360a2e7cc232b)"));
+    assert_eq!(reconstructed(&stdout), "kat");
+
+    // A second, independent refresh of the same shares gets its own epoch, so
+    // its shares cannot be mixed with the first one's.
+    let other_seed = format!("ff{}", &SEED[2..]);
+    let other = cli_shares(&sss(&["refresh", "-k", "3", "-s", split, "--seed-hex", &other_seed]));
+    let other_epoch = other.split(':').next().unwrap();
+    assert_ne!(other_epoch, "gab2360a2e7cc232b/3");
+
+    let mixed: Vec<&str> = expected.split(';').take(2).chain(other.split(';').skip(2)).collect();
+    let output = Command::new(env!("CARGO_BIN_EXE_sss"))
+        .args(["reconstruct", "-s", &mixed.join(";")])
+        .output()
+        .expect("failed to run sss");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_reshare_vector() {
+    // Holders 1, 3 and 5 of the k = 3 split vector move the secret to 2 of 4.
+    let old = "1,291681323697066651986166888299408364601;\
+               3,339185976240357270314302951905982758432;\
+               5,239350580517421746889133077242533156730";
+    let expected = "g2da3f7b1ea358225/2:1,160079229750055076807615704074838353460;\
+                    g2da3f7b1ea358225/2:2,320158459500110153615231408149669669620;\
+                    g2da3f7b1ea358225/2:3,139955322329226766959472504792732774483;\
+                    g2da3f7b1ea358225/2:4,300034552079281843767088208867564090643";
+    let shares = cli_shares(&sss(&["reshare", "--old-k", "3", "-n", "4", "-k", "2", "-s", old, "--seed-hex", SEED]));
+    assert_eq!(shares, expected);
+
+    let some: Vec<&str> = expected.split(';').skip(2).collect();
+    assert_eq!(reconstructed(&sss(&["reconstruct", "-s", &some.join(";")])), "kat");
+}
+
+#[test]
+fn test_seed_hex_rejects_non_hex() {
+    let seed = format!("+f{}", &SEED[2..]);
+    let output = Command::new(env!("CARGO_BIN_EXE_sss"))
+        .args(["split", "-s", "kat", "-n", "3", "-k", "2", "--seed-hex", &seed])
+        .output()
+        .expect("failed to run sss");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,175 @@
+//! Bracha reliable broadcast of the dealer's commitment list.
+//!
+//! With n holders of which at most f are faulty (n >= 3f + 1):
+//! - on SEND(C) from the dealer, echo ECHO(C) to everyone (once);
+//! - on ⌈(n + f + 1) / 2⌉ ECHO(C), send READY(C) (once);
+//! - on f + 1 READY(C), send READY(C) if not sent yet;
+//! - on 2f + 1 READY(C), deliver C.
+//!
+//! Either every honest holder delivers the same C or none delivers anything,
+//! so a dealer that sends different commitments to different holders is caught.
+
+use std::collections::{HashMap, HashSet};
+use num_bigint::BigUint;
+use super::bus::{Bus, Node};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Message {
+    Send(Vec<BigUint>),
+    Echo(Vec<BigUint>),
+    Ready(Vec<BigUint>),
+}
+
+/// Largest f with n >= 3f + 1.
+pub fn max_faulty(n: usize) -> usize {
+    n.saturating_sub(1) / 3
+}
+
+pub fn echo_quorum(n: usize, f: usize) -> usize {
+    (n + f + 1).div_ceil(2)
+}
+
+pub struct Holder {
+    id: usize,
+    n: usize,
+    f: usize,
+    received: Option<Vec<BigUint>>,
+    sent_ready: bool,
+    echoes: HashMap<Vec<BigUint>, HashSet<usize>>,
+    readies: HashMap<Vec<BigUint>, HashSet<usize>>,
+    delivered: Option<Vec<BigUint>>,
+}
+
+impl Holder {
+    pub fn new(id: usize, n: usize, f: usize) -> Self {
+        Holder {
+            id,
+            n,
+            f,
+            received: None,
+            sent_ready: false,
+            echoes: HashMap::new(),
+            readies: HashMap::new(),
+            delivered: None,
+        }
+    }
+
+    /// Commitments this holder got directly from the dealer.
+    pub fn received(&self) -> Option<&Vec<BigUint>> {
+        self.received.as_ref()
+    }
+
+    /// Commitments agreed on through the broadcast.
+    pub fn delivered(&self) -> Option<&Vec<BigUint>> {
+        self.delivered.as_ref()
+    }
+
+    /// Handles one message and returns what this holder broadcasts in reply.
+    pub fn handle(&mut self, from: Node, msg: Message) -> Vec<Message> {
+        let mut out = Vec::new();
+
+        match (from, msg) {
+            // Only the first SEND counts, so each holder echoes at most once.
+            (Node::Dealer, Message::Send(commitments)) if self.received.is_none() => {
+                self.received = Some(commitments.clone());
+                out.push(Message::Echo(commitments));
+            }
+
+            (Node::Holder(sender), Message::Echo(commitments)) => {
+                let voters = self.echoes.entry(commitments.clone()).or_default();
+                voters.insert(sender);
+                if voters.len() >= echo_quorum(self.n, self.f) && !self.sent_ready {
+                    self.sent_ready = true;
+                    out.push(Message::Ready(commitments));
+                }
+            }
+
+            (Node::Holder(sender), Message::Ready(commitments)) => {
+                let voters = self.readies.entry(commitments.clone()).or_default();
+                voters.insert(sender);
+                let count = voters.len();
+
+                if count > self.f && !self.sent_ready {
+                    self.sent_ready = true;
+                    out.push(Message::Ready(commitments.clone()));
+                }
+                if count > 2 * self.f && self.delivered.is_none() {
+                    self.delivered = Some(commitments);
+                }
+            }
+
+            // SEND only counts from the dealer; ECHO and READY only from holders.
+            _ => {}
+        }
+
+        out
+    }
+}
+
+/// Runs one broadcast to completion. `sends[i]` is what the dealer sends holder i
+/// (an honest dealer sends the same list to everyone). Holders listed in `silent`
+/// crash and never send anything.
+pub fn run(sends: &[Vec<BigUint>], f: usize, silent: &[usize]) -> Vec<Holder> {
+    let n = sends.len();
+    let mut holders: Vec<Holder> = (0..n).map(|id| Holder::new(id, n, f)).collect();
+    let mut bus = Bus::new();
+
+    for (to, commitments) in sends.iter().enumerate() {
+        bus.send(Node::Dealer, to, Message::Send(commitments.clone()));
+    }
+
+    while let Some(envelope) = bus.pop() {
+        let holder = &mut holders[envelope.to];
+        let replies = holder.handle(envelope.from, envelope.msg);
+
+        if silent.contains(&holder.id) {
+            continue;
+        }
+        for reply in replies {
+            bus.broadcast(Node::Holder(holder.id), n, reply);
+        }
+    }
+
+    holders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commitments(seed: u32) -> Vec<BigUint> {
+        vec![BigUint::from(seed), BigUint::from(seed + 1)]
+    }
+
+    #[test]
+    fn test_honest_dealer_is_delivered_everywhere() {
+        let sends = vec![commitments(10); 4];
+        let holders = run(&sends, max_faulty(4), &[3]);
+
+        for holder in &holders {
+            assert_eq!(holder.delivered(), Some(&commitments(10)));
+        }
+    }
+
+    #[test]
+    fn test_equivocating_dealer_is_not_delivered() {
+        let sends = vec![commitments(10), commitments(10), commitments(20), commitments(20)];
+        let holders = run(&sends, max_faulty(4), &[]);
+
+        for holder in &holders {
+            assert_eq!(holder.delivered(), None);
+        }
+    }
+
+    #[test]
+    fn test_minority_equivocation_converges() {
+        let mut sends = vec![commitments(10); 7];
+        sends[6] = commitments(20);
+        let holders = run(&sends, max_faulty(7), &[]);
+
+        for holder in &holders {
+            assert_eq!(holder.delivered(), Some(&commitments(10)));
+        }
+        assert_eq!(holders[6].received(), Some(&commitments(20)));
+    }
+}
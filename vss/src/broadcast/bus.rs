@@ -0,0 +1,46 @@
+use std::collections::VecDeque;
+
+/// Who sent a message. The dealer is not one of the n holders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Node {
+    Dealer,
+    Holder(usize),
+}
+
+pub struct Envelope<M> {
+    pub from: Node,
+    pub to: usize,
+    pub msg: M,
+}
+
+/// In-process stand-in for the network: a single FIFO queue of point-to-point
+/// messages addressed to holders 0..n.
+pub struct Bus<M> {
+    queue: VecDeque<Envelope<M>>,
+}
+
+impl<M> Default for Bus<M> {
+    fn default() -> Self {
+        Bus { queue: VecDeque::new() }
+    }
+}
+
+impl<M: Clone> Bus<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn send(&mut self, from: Node, to: usize, msg: M) {
+        self.queue.push_back(Envelope { from, to, msg });
+    }
+
+    pub fn broadcast(&mut self, from: Node, n: usize, msg: M) {
+        for to in 0..n {
+            self.send(from, to, msg.clone());
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<Envelope<M>> {
+        self.queue.pop_front()
+    }
+}
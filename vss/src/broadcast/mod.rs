@@ -0,0 +1,2 @@
+pub mod bus;
+pub mod bracha;
\ No newline at end of file
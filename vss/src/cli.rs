@@ -1,41 +1,347 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
+#[command(after_help = "Secrets, shares and commitments also accept `-` (stdin) or `@path` (a file).")]
 pub struct Args {
     #[command(subcommand)]
     pub cmd: Command,
+    /// Output format; json prints one structured object per command
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
 }
 
 #[derive(Subcommand)]
 pub enum Command {
     Split {
+        /// Secret; `-` reads stdin, `@file` reads a file, omit to be prompted
         #[arg(long)]
-        secret: String,
-        #[arg(long)]
-        n: usize,
+        secret: Option<String>,
+        #[arg(long, required_unless_present_any = ["indices", "ids_file"])]
+        n: Option<usize>,
         #[arg(long)]
         k: usize,
+        /// Comma-separated x-coordinates to deal to instead of 1..n
+        #[arg(long, conflicts_with = "ids_file")]
+        indices: Option<String>,
+        /// One identifier per line; each holder's x is derived from its identifier
+        #[arg(long)]
+        ids_file: Option<String>,
+        /// Share a random data key and write the secret, encrypted under it, to this file
+        #[arg(long)]
+        hybrid: Option<String>,
+        /// File to encrypt instead of --secret (hybrid mode only)
+        #[arg(long, conflicts_with = "secret", requires = "hybrid")]
+        file: Option<String>,
+        /// Write the public dealing record (commitments and issued indices) here
+        #[arg(long)]
+        transcript: Option<String>,
     },
 
     VerifySecret {
+        /// Secret; `-` reads stdin, `@file` reads a file, omit to be prompted
         #[arg(long,short)]
-        secret: String,
+        secret: Option<String>,
         #[arg(long,short)]
         commitments: String,
     },
 
     VerifyShare {
+        /// Shares "x1,y1;x2,y2"; `-` or `@file` read one share per line
         #[arg(long,short)]
-        share: String,       
+        share: Option<String>,
         #[arg(long,short)]
-        commitments: String,
+        commitments: Option<String>,
         #[arg(long, default_value_t = false)]
         verbose: bool,
+        /// Keystore entry to use instead of --share
+        #[arg(long)]
+        entry: Option<String>,
+        #[arg(long, default_value = "vss.keystore")]
+        keystore: String,
+        /// Dealing transcript: supplies the commitments and accepts shares as "id:y"
+        #[arg(long)]
+        transcript: Option<String>,
     },
     
     Reconstruct {
+        /// Shares "x1,y1;x2,y2"; `-` or `@file` read one share per line
+        #[arg(long,short)]
+        shares: Option<String>,
+        /// Comma-separated keystore entries to use instead of --shares
+        #[arg(long)]
+        entries: Option<String>,
+        #[arg(long, default_value = "vss.keystore")]
+        keystore: String,
+        /// Print every step of the Lagrange interpolation
+        #[arg(long, default_value_t = false)]
+        verbose: bool,
+        /// Hybrid payload to decrypt with the reconstructed data key
+        #[arg(long)]
+        payload: Option<String>,
+        /// Write the decrypted payload here instead of printing it
+        #[arg(long, requires = "payload")]
+        out: Option<String>,
+        /// Dealing transcript: accepts shares as "id:y" and checks every x was issued
+        #[arg(long)]
+        transcript: Option<String>,
+    },
+
+    Encrypt {
+        /// Message; `-` reads stdin, `@file` reads a file, omit to be prompted
+        #[arg(long,short)]
+        message: Option<String>,
+        #[arg(long,short)]
+        commitments: String,
+    },
+
+    PartialDecrypt {
+        #[arg(long,short)]
+        share: String,
+        #[arg(long)]
+        ciphertext: String,
+    },
+
+    Decrypt {
+        #[arg(long)]
+        ciphertext: String,
+        #[arg(long)]
+        commitments: String,
+        #[arg(long,short)]
+        partials: String,
+    },
+
+    /// Per-holder verification keys g^f(i) computed from the commitments
+    PublicKeys {
+        #[arg(long,short)]
+        commitments: String,
+        #[arg(long,short)]
+        indices: String,
+    },
+
+    /// Checks a claimed group public key C[0] against k holder keys g^f(i)
+    AuditPublicKey {
+        #[arg(long,short)]
+        public_keys: String,
+        #[arg(long)]
+        k: usize,
+        #[arg(long)]
+        claimed: String,
+    },
+
+    /// Split with the commitments agreed on through Bracha reliable broadcast
+    RobustSplit {
+        /// Secret; `-` reads stdin, `@file` reads a file, omit to be prompted
+        #[arg(long)]
+        secret: Option<String>,
+        #[arg(long)]
+        n: usize,
+        #[arg(long)]
+        k: usize,
+        /// Simulate a dealer that sends different commitments to this many holders
+        #[arg(long, default_value_t = 0)]
+        equivocate: usize,
+        /// Holders (by x) that never send echo or ready messages, e.g. "2,5"
+        #[arg(long)]
+        silent: Option<String>,
+    },
+
+    /// Shareholder daemon answering verification and masked-share requests over TCP
+    Serve {
+        #[arg(long)]
+        listen: String,
+        #[arg(long,short)]
+        share: String,
+        /// Commitments of the split; the masks are derived from them
+        #[arg(long,short)]
+        commitments: String,
+        #[arg(long)]
+        token: String,
+    },
+
+    /// Collects masked shares from k running daemons and interpolates the secret
+    Coordinate {
+        /// Daemon addresses, e.g. "127.0.0.1:7001,127.0.0.1:7002"
+        #[arg(long)]
+        holders: String,
+        #[arg(long)]
+        k: usize,
+        #[arg(long)]
+        token: String,
+        #[arg(long,short)]
+        commitments: Option<String>,
+    },
+
+    /// Simulates share recovery with all k helpers in one process; see `recover` for the per-helper steps
+    RecoverShare {
+        /// Helpers' shares "x1,y1;x2,y2"; `-` or `@file` read one share per line
+        #[arg(long,short)]
+        shares: String,
+        /// x-coordinate of the lost share
+        #[arg(long)]
+        index: String,
+        #[arg(long,short)]
+        commitments: String,
+        /// Simulate a helper (by x) that forwards a wrong sum
+        #[arg(long)]
+        corrupt: Option<String>,
+    },
+
+    /// Issues a share at a new index from k existing holders, simulated in one process
+    #[command(group = clap::ArgGroup::new("new_holder").required(true).args(["index", "id"]))]
+    Enroll {
+        /// Helpers' shares "x1,y1;x2,y2"; `-` or `@file` read one share per line
         #[arg(long,short)]
         shares: String,
+        /// x-coordinate for the new shareholder
+        #[arg(long)]
+        index: Option<String>,
+        /// Identifier for the new shareholder; x is derived from it
+        #[arg(long)]
+        id: Option<String>,
+        #[arg(long,short, conflicts_with = "transcript")]
+        commitments: Option<String>,
+        /// Dealing transcript to take the commitments from and record the new index in
+        #[arg(long)]
+        transcript: Option<String>,
+    },
+
+    /// Rebuilds a lost share (or issues one at a new index), one step per party
+    Recover {
+        #[command(subcommand)]
+        cmd: RecoverCommand,
+    },
+
+    /// Passphrase-encrypted storage for shares and commitments
+    Keystore {
+        #[arg(long, default_value = "vss.keystore")]
+        path: String,
+        #[command(subcommand)]
+        cmd: KeystoreCommand,
+    },
+
+    /// Publicly verifiable secret sharing (Schoenmakers)
+    Pvss {
+        #[command(subcommand)]
+        cmd: PvssCommand,
+    },
+
+}
+
+#[derive(Subcommand)]
+pub enum RecoverCommand {
+    /// Round 1, run by each helper: splits its weighted share into blinded pieces
+    Contribute {
+        /// This helper's share "x,y"; `-` reads stdin, `@file` reads a file
+        #[arg(long,short)]
+        share: String,
+        /// x-coordinates of all helpers, in the order every party uses
+        #[arg(long)]
+        helpers: String,
+        /// x-coordinate of the share being rebuilt
+        #[arg(long)]
+        index: String,
+    },
+
+    /// Round 2, run by each helper: adds up the pieces it was sent
+    Forward {
+        /// Pieces received from every helper, "d1,d2,d3"
+        #[arg(long)]
+        pieces: String,
+    },
+
+    /// Run by the recovering holder: checks every helper and rebuilds the share
+    Combine {
+        /// x-coordinate of the share being rebuilt
+        #[arg(long)]
+        index: String,
+        /// x-coordinates of all helpers, in the order used by `contribute`
+        #[arg(long)]
+        helpers: String,
+        /// Each helper's published piece commitments, one row per helper "E,E,E;E,E,E"
+        #[arg(long)]
+        piece_commitments: String,
+        /// Each helper's forwarded sum, in helper order
+        #[arg(long)]
+        sums: String,
+        #[arg(long,short)]
+        commitments: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum KeystoreCommand {
+    /// Creates an empty keystore, asking for the new passphrase twice
+    Create,
+
+    Import {
+        #[arg(long)]
+        name: String,
+        #[arg(long,short)]
+        share: String,
+        #[arg(long,short)]
+        commitments: Option<String>,
+    },
+
+    List,
+
+    Export {
+        #[arg(long)]
+        name: String,
+    },
+
+    Delete {
+        #[arg(long)]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PvssCommand {
+    Keygen,
+
+    Deal {
+        /// Secret; `-` reads stdin, `@file` reads a file, omit to be prompted
+        #[arg(long,short)]
+        secret: Option<String>,
+        #[arg(long)]
+        k: usize,
+        #[arg(long)]
+        public_keys: String,
     },
 
+    Verify {
+        #[arg(long)]
+        commitments: String,
+        #[arg(long)]
+        public_keys: String,
+        #[arg(long)]
+        encrypted_shares: String,
+    },
+
+    Decrypt {
+        /// Private key; `-` reads stdin, `@file` reads a file, omit to be prompted
+        #[arg(long)]
+        private_key: Option<String>,
+        #[arg(long)]
+        encrypted_share: String,
+    },
+
+    Reconstruct {
+        #[arg(long)]
+        commitments: String,
+        #[arg(long)]
+        public_keys: String,
+        #[arg(long)]
+        encrypted_shares: String,
+        #[arg(long)]
+        decrypted_shares: String,
+        #[arg(long)]
+        masked_secret: String,
+    },
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use crate::crypto::params;
+use serde_json::json;
+use crate::math::lagrange;
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(public_keys: String, k: usize, claimed: String) {
+    let (p, q, _g) = params::get_fixed_params();
+
+    let key_list: Vec<(BigUint, BigUint)> = public_keys
+        .split(';')
+        .map(|s| {
+            let values = parse::tuple(s, 2, "x,g^f(x)");
+            (values[0].clone(), values[1].clone())
+        })
+        .collect();
+
+    let claimed_key = parse::number(&claimed, "claimed public key");
+
+    if k == 0 || key_list.len() < k {
+        output::fail(
+            Failure::Params,
+            format!("Need at least k = {} holder public keys, got {}", k, key_list.len()),
+        );
+    }
+
+    say!("Audit Public Key Mode");
+    say!("Holder public keys: {}", key_list.len());
+    say!("Threshold k = {}", k);
+    say!();
+
+    let (base, rest) = key_list.split_at(k);
+
+    let commitments = match lagrange::coefficients_in_exponent(base, &p, &q) {
+        Some(commitments) => commitments,
+        None => output::fail(Failure::Params, "Public keys must have distinct x coordinates"),
+    };
+    let group_key = lagrange::interpolate_in_exponent(base, &BigUint::zero(), &p, &q)
+        .expect("x coordinates were already checked");
+
+    say!("Recovered commitments:");
+    for (i, commitment) in commitments.iter().enumerate() {
+        say!("C[{}] = {}", i, commitment);
+    }
+    say!();
+
+    let commitments_str: Vec<String> = commitments
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    say!("Commitments (CLI format):");
+    say!("{}", commitments_str.join(","));
+    say!();
+
+    // Every key beyond the first k must lie on the same degree k-1 polynomial.
+    let mut consistent = true;
+    let mut holders = Vec::new();
+    for (x, key) in rest {
+        let expected = lagrange::interpolate_in_exponent(base, x, &p, &q)
+            .expect("x coordinates were already checked");
+        if expected == *key {
+            say!("Holder x={}: consistent", x);
+        } else {
+            say!("Holder x={}: INCONSISTENT with the first {} keys", x, k);
+            consistent = false;
+        }
+        holders.push(json!({ "x": x.to_string(), "consistent": expected == *key }));
+    }
+    if !rest.is_empty() {
+        say!();
+    }
+
+    say!("Claimed public key: {}", claimed_key);
+    say!("Interpolated g^f(0): {}", group_key);
+    say!();
+
+    let matches = group_key == claimed_key;
+    if !consistent {
+        say!("✗ The holders' keys do not lie on one polynomial of degree {}!", k - 1);
+    } else if matches {
+        say!("✓ Public key verified successfully!");
+        say!("  The claimed key matches the holders' keys.");
+    } else {
+        say!("✗ Public key verification failed!");
+        say!("  The claimed key does NOT match the holders' keys.");
+    }
+
+    output::emit(json!({
+        "valid": consistent && matches,
+        "consistent": consistent,
+        "claimed": claimed_key.to_string(),
+        "interpolated": group_key.to_string(),
+        "commitments": output::numbers(&commitments),
+        "extra_holders": holders,
+    }));
+    output::exit_if_invalid(consistent && matches);
+}
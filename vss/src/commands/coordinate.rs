@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use rand::RngCore;
+use crate::crypto::params;
+use crate::math::lagrange;
+use crate::net::client::Connection;
+use crate::net::protocol;
+use serde_json::json;
+use super::output::{self, Failure};
+use super::parse;
+
+/// Sets of k holders asked before giving up.
+const MAX_ATTEMPTS: usize = 64;
+
+pub fn execute(holders: String, k: usize, token: String, commitments: Option<String>) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let commitment_list: Option<Vec<BigUint>> = commitments
+        .map(|commitments| parse::numbers(&commitments, "commitment"));
+
+    let addresses: Vec<&str> = holders.split(',').map(|s| s.trim()).collect();
+
+    say!("Coordinate Mode");
+    say!("Holders: {}", addresses.len());
+    say!("Threshold k = {}", k);
+    say!();
+
+    if k == 0 {
+        output::fail(Failure::Params, "k must be at least 1");
+    }
+
+    // Find every reachable holder and its public index.
+    let mut pool: Vec<(&str, BigUint)> = Vec::new();
+    let mut results = Vec::new();
+    for address in addresses {
+        let reply = Connection::connect(address, &token).and_then(|mut connection| {
+            let self_check = match &commitment_list {
+                Some(commitment_list) => Some(connection.verify(commitment_list)?),
+                None => None,
+            };
+            let x = connection.index()?;
+            connection.quit()?;
+            Ok((x, self_check))
+        });
+        let (x, self_check) = match reply {
+            Ok(reply) => reply,
+            Err(e) => {
+                say!("{}: UNAVAILABLE - {}", address, e);
+                results.push(json!({ "holder": address, "status": "unavailable", "error": e.to_string() }));
+                continue;
+            }
+        };
+
+        if self_check == Some(false) {
+            say!("{}: daemon reports its share x={} as INVALID", address, x);
+        }
+        if pool.iter().any(|(_, other)| *other == x) {
+            say!("{}: DUPLICATE - index x={} already in the set", address, x);
+            results.push(json!({ "holder": address, "x": x.to_string(), "status": "duplicate" }));
+            continue;
+        }
+        pool.push((address, x));
+    }
+
+    // Each holder answers with a masked share for one set and one nonce. The
+    // answers cannot be checked one by one, so a set whose result does not match
+    // C[0] is dropped as a whole and another set is asked.
+    let mut tried: HashSet<Vec<BigUint>> = HashSet::new();
+    let mut rejected_sets = Vec::new();
+    let (chosen, reconstructed) = loop {
+        let chosen = match untried_set(&pool, k, &tried) {
+            Some(chosen) if tried.len() < MAX_ATTEMPTS => chosen,
+            _ => {
+                let kind = if rejected_sets.is_empty() { Failure::Io } else { Failure::Invalid };
+                output::fail_with(
+                    kind,
+                    format!("No set of {} holders reconstructs the secret\n  - Reachable holders: {}", k, pool.len()),
+                    json!({ "holders": results, "rejected_sets": rejected_sets, "valid": false }),
+                );
+            }
+        };
+        let xs: Vec<BigUint> = chosen.iter().map(|&i| pool[i].1.clone()).collect();
+
+        let mut nonce = [0u8; protocol::NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut points = Vec::new();
+        let mut unavailable = None;
+        for &i in &chosen {
+            let (address, x) = &pool[i];
+            let reply = Connection::connect(address, &token).and_then(|mut connection| {
+                let masked = connection.masked_share(&nonce, &xs)?;
+                connection.quit()?;
+                Ok(masked)
+            });
+            match reply {
+                Ok(masked) => points.push((x.clone(), masked)),
+                Err(e) => {
+                    say!("{}: UNAVAILABLE - {}", address, e);
+                    results.push(json!({ "holder": address, "x": x.to_string(), "status": "unavailable", "error": e.to_string() }));
+                    unavailable = Some(i);
+                    break;
+                }
+            }
+        }
+        if let Some(i) = unavailable {
+            pool.remove(i);
+            say!("Retrying with a new set of holders");
+            continue;
+        }
+
+        tried.insert(sorted(&xs));
+        let secret = match lagrange::interpolate(&points, &q) {
+            Ok(secret) => secret,
+            Err(e) => output::fail(Failure::Params, format!("Cannot interpolate: {}", e)),
+        };
+
+        // Never trust a result the commitments do not vouch for: g^s must be C[0].
+        if let Some(commitment_list) = &commitment_list
+            && g.modpow(&secret, &p) != commitment_list[0]
+        {
+            let set = protocol::format_numbers(&xs);
+            say!("Set {{{}}}: INVALID - the result does not match C[0]; some holder in it cheated", set);
+            rejected_sets.push(json!(xs.iter().map(|x| x.to_string()).collect::<Vec<_>>()));
+            say!("Retrying with a new set of holders");
+            continue;
+        }
+        break (chosen, secret);
+    };
+
+    for &i in &chosen {
+        let (address, x) = &pool[i];
+        say!("{}: collected masked share from x={}", address, x);
+        results.push(json!({ "holder": address, "x": x.to_string(), "status": "collected" }));
+    }
+    if commitment_list.is_none() {
+        say!("No commitments given; the result is unchecked");
+    }
+    say!();
+
+    output::say_secret("Secret", &reconstructed);
+
+    output::emit(json!({
+        "holders": results,
+        "rejected_sets": rejected_sets,
+        "valid": true,
+        "secret": output::encodings(&reconstructed),
+    }));
+}
+
+fn sorted(xs: &[BigUint]) -> Vec<BigUint> {
+    let mut xs = xs.to_vec();
+    xs.sort();
+    xs
+}
+
+/// The first k-subset of the pool, in lexicographic order of positions, whose
+/// indices have not been tried yet.
+fn untried_set(pool: &[(&str, BigUint)], k: usize, tried: &HashSet<Vec<BigUint>>) -> Option<Vec<usize>> {
+    if k > pool.len() {
+        return None;
+    }
+    let mut set: Vec<usize> = (0..k).collect();
+    loop {
+        let xs: Vec<BigUint> = set.iter().map(|&i| pool[i].1.clone()).collect();
+        if !tried.contains(&sorted(&xs)) {
+            return Some(set);
+        }
+
+        // Advance to the next combination.
+        let position = (0..k).rev().find(|&j| set[j] < pool.len() - k + j)?;
+        set[position] += 1;
+        for j in position + 1..k {
+            set[j] = set[j - 1] + 1;
+        }
+    }
+}
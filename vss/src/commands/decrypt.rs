@@ -0,0 +1,74 @@
+use serde_json::json;
+use crate::crypto::{params, dleq, elgamal, threshold};
+use crate::crypto::threshold::PartialDecryption;
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(ciphertext: String, commitments: String, partials: String) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let ciphertext = parse::ciphertext(&ciphertext);
+    let commitment_list = parse::numbers(&commitments, "commitment");
+
+    let partial_list: Vec<PartialDecryption> = partials
+        .split(';')
+        .map(|s| {
+            let values = parse::tuple(s, 4, "x,d,challenge,response");
+            PartialDecryption {
+                x: values[0].clone(),
+                d: values[1].clone(),
+                proof: dleq::Proof {
+                    challenge: values[2].clone(),
+                    response: values[3].clone(),
+                },
+            }
+        })
+        .collect();
+
+    let k = commitment_list.len();
+
+    say!("Decrypt Mode");
+    say!("Partial decryptions provided: {}", partial_list.len());
+    say!("Threshold k = {}", k);
+    say!();
+
+    let mut valid_partials = Vec::new();
+    let mut results = Vec::new();
+    for partial in &partial_list {
+        let is_valid = threshold::verify_partial(&ciphertext, partial, &commitment_list, &g, &p, &q);
+        if is_valid {
+            say!("Partial from x={}: VALID", partial.x);
+            if !valid_partials.iter().any(|v: &PartialDecryption| v.x == partial.x) {
+                valid_partials.push(partial.clone());
+            }
+        } else {
+            say!("Partial from x={}: INVALID - proof of correct decryption failed, ignoring", partial.x);
+        }
+        results.push(json!({ "x": partial.x.to_string(), "valid": is_valid }));
+    }
+    say!();
+
+    if valid_partials.len() < k {
+        let kind = if results.len() > valid_partials.len() { Failure::Invalid } else { Failure::Params };
+        output::fail_with(
+            kind,
+            format!("Not enough valid partial decryptions\n  - Need {}, got {}", k, valid_partials.len()),
+            json!({ "partials": results, "valid": false }),
+        );
+    }
+    valid_partials.truncate(k);
+
+    let encoded = match threshold::combine(&ciphertext, &valid_partials, &p, &q) {
+        Some(encoded) => encoded,
+        None => output::fail(Failure::Params, "Partial decryptions must have distinct x coordinates"),
+    };
+    let message = elgamal::decode(&encoded, &p, &q);
+
+    output::say_secret("Message", &message);
+
+    output::emit(json!({
+        "partials": results,
+        "valid": true,
+        "message": output::encodings(&message),
+    }));
+}
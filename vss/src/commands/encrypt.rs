@@ -0,0 +1,35 @@
+use serde_json::json;
+use crate::crypto::{params, elgamal};
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(message: String, commitments: String) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let commitment_list = parse::numbers(&commitments, "commitment");
+    let public_key = &commitment_list[0];
+
+    let message_num = parse::secret(&message, "Message", &q);
+    let encoded = elgamal::encode(&message_num, &p, &q).expect("encoded messages are below q");
+
+    say!("Encrypt Mode");
+    say!("Message: {}", message);
+    say!("Public key (C[0]): {}", public_key);
+    say!();
+
+    let Some(ciphertext) = elgamal::encrypt(&encoded, public_key, &g, &p, &q) else {
+        output::fail(Failure::Invalid, "The public key C[0] is not in the order-q subgroup");
+    };
+
+    say!("c1 = {}", ciphertext.c1);
+    say!("c2 = {}", ciphertext.c2);
+    say!();
+
+    say!("Ciphertext (CLI format):");
+    say!("{},{}", ciphertext.c1, ciphertext.c2);
+
+    output::emit(json!({
+        "public_key": public_key.to_string(),
+        "ciphertext": { "c1": ciphertext.c1.to_string(), "c2": ciphertext.c2.to_string() },
+    }));
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+use num_bigint::BigUint;
+use serde_json::json;
+use crate::crypto::params;
+use crate::transcript::{self, Holder};
+use super::output::{self, Failure};
+use super::{holders, parse, recover_share};
+
+/// Issues a share at a new index from k existing holders. The secret, the
+/// threshold and the commitments stay the same; only n grows.
+pub fn execute(
+    shares: String,
+    index: Option<String>,
+    id: Option<String>,
+    commitments: Option<String>,
+    transcript_path: Option<String>,
+) {
+    let (_p, q, _g) = params::get_fixed_params();
+    let mut transcript = transcript_path.as_deref().map(holders::load);
+
+    let shares = match &transcript {
+        Some(transcript) => holders::resolve_shares(&shares, transcript),
+        None => shares,
+    };
+    let helpers = parse::shares(&shares);
+
+    let holder = match (index, id) {
+        (Some(index), None) => Holder { x: parse::number(&index, "index"), id: None },
+        (None, Some(id)) => Holder { x: transcript::id_to_x(&id, &q), id: Some(id) },
+        _ => output::fail(Failure::Params, "Provide exactly one of --index or --id"),
+    };
+    let target = holder.x.clone();
+
+    let commitment_list: Vec<BigUint> = match (&commitments, &transcript) {
+        (Some(commitments), None) => parse::numbers(commitments, "commitment"),
+        (None, Some(transcript)) => transcript.commitments.clone(),
+        _ => output::fail(Failure::Params, "Provide exactly one of --commitments or --transcript"),
+    };
+
+    // Check the index before running the protocol, so a taken index costs nothing.
+    if let Some(transcript) = &transcript
+        && let Err(e) = transcript.clone().enroll(holder.clone())
+    {
+        output::fail(Failure::Params, format!("Cannot enroll x={}: {}", target, e));
+    }
+
+    say!("Enroll Mode");
+    match &holder.id {
+        Some(id) => say!("New holder: {} (x = {})", id, target),
+        None => say!("New index: {}", target),
+    }
+
+    let (y, results) = recover_share::run(&helpers, &target, &commitment_list, None);
+    let Some(y) = y else {
+        output::fail_with(
+            Failure::Invalid,
+            "The new share does not verify; nothing was recorded",
+            json!({ "valid": false, "helpers": results }),
+        );
+    };
+
+    if let (Some(transcript), Some(path)) = (&mut transcript, &transcript_path) {
+        transcript.enroll(holder.clone()).expect("holder was checked above");
+        if let Err(e) = transcript.save(Path::new(path)) {
+            output::fail(Failure::Io, format!("Cannot write transcript '{}': {}", path, e));
+        }
+        say!();
+        say!("Transcript {} now lists {} holders.", path, transcript.holders.len());
+    }
+
+    output::emit(json!({
+        "valid": true,
+        "helpers": results,
+        "share": { "x": target.to_string(), "y": y.to_string(), "id": holder.id },
+        "holders": transcript.map(|transcript| transcript
+            .holders
+            .iter()
+            .map(|holder| json!({ "x": holder.x.to_string(), "id": holder.id }))
+            .collect::<Vec<_>>()),
+    }));
+}
@@ -0,0 +1,106 @@
+//! Which x-coordinate belongs to which shareholder.
+//!
+//! `split` assigns x = 1..n by default, explicit `--indices`, or x = id_to_x(id)
+//! for every identifier in `--ids-file`. The result is recorded in the dealing
+//! transcript, so later commands can accept shares named `id:y` and check that
+//! every `x,y` share was actually issued.
+
+use std::fs;
+use std::path::Path;
+use num_bigint::BigUint;
+use crate::crypto::params;
+use crate::transcript::{self, Holder, Transcript};
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn load(path: &str) -> Transcript {
+    let transcript = match Transcript::load(Path::new(path)) {
+        Ok(transcript) => transcript,
+        Err(e) => output::fail(Failure::Io, format!("Cannot read transcript '{}': {}", path, e)),
+    };
+    if transcript.params != params::get_fixed_params() {
+        output::fail(Failure::Params, "The transcript was made with different group parameters");
+    }
+    transcript
+}
+
+/// A transcript without commitments yet, listing the holders `split` should deal to.
+pub fn plan(n: Option<usize>, indices: Option<String>, ids_file: Option<String>) -> Transcript {
+    let (_p, q, _g) = params::get_fixed_params();
+
+    let holders: Vec<Holder> = match (indices, ids_file) {
+        (Some(indices), None) => parse::numbers(&indices, "index")
+            .into_iter()
+            .map(|x| Holder { x, id: None })
+            .collect(),
+        (None, Some(path)) => {
+            let text = match fs::read_to_string(&path) {
+                Ok(text) => text,
+                Err(e) => output::fail(Failure::Io, format!("Cannot read identifiers from '{}': {}", path, e)),
+            };
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|id| Holder { x: transcript::id_to_x(id, &q), id: Some(id.to_string()) })
+                .collect()
+        }
+        (None, None) => (1..=n.unwrap_or(0))
+            .map(|i| Holder { x: BigUint::from(i as u64), id: None })
+            .collect(),
+        (Some(_), Some(_)) => output::fail(Failure::Params, "Use either --indices or --ids-file, not both"),
+    };
+
+    if let Some(n) = n
+        && n != holders.len()
+    {
+        output::fail(
+            Failure::Params,
+            format!("--n is {} but {} holders were listed", n, holders.len()),
+        );
+    }
+
+    let mut plan = Transcript {
+        params: params::get_fixed_params(),
+        commitments: Vec::new(),
+        holders: Vec::new(),
+    };
+    for holder in holders {
+        if holder.id.as_deref().is_some_and(|id| id.contains(';')) {
+            output::fail(Failure::Params, "Identifiers must not contain ';'");
+        }
+        if let Err(e) = plan.enroll(holder) {
+            output::fail(Failure::Params, format!("Invalid holder: {}", e));
+        }
+    }
+    plan
+}
+
+/// Rewrites "alice:y" as "x,y" with alice's x from the transcript, and refuses
+/// "x,y" shares whose x was never issued.
+pub fn resolve_shares(list: &str, transcript: &Transcript) -> String {
+    list.split(';')
+        .map(|share| match share.trim().rsplit_once(':') {
+            Some((id, y)) => match transcript.find(id.trim()) {
+                Some(holder) => format!("{},{}", holder.x, y.trim()),
+                None => output::fail(Failure::Params, format!("No holder '{}' in the transcript", id.trim())),
+            },
+            None => {
+                let (x, _) = parse::share(share);
+                if !transcript.is_issued(&x) {
+                    output::fail(Failure::Params, format!("No share at x={} was issued in the transcript", x));
+                }
+                share.trim().to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+pub fn commitments_string(transcript: &Transcript) -> String {
+    transcript
+        .commitments
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
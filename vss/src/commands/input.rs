@@ -0,0 +1,83 @@
+//! Where argument values come from, so secrets never have to sit in argv.
+//!
+//! `-` reads the value from stdin and `@path` from a file; anything else is taken
+//! literally. Secrets that are left out entirely are prompted for without echo.
+//! Share and commitment files may hold one value per line; blank lines and lines
+//! starting with `#` are skipped.
+
+use std::fs;
+use std::io::{self, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use super::output::{self, Failure};
+
+static STDIN_TAKEN: AtomicBool = AtomicBool::new(false);
+
+fn read_source(value: &str, what: &str) -> Option<String> {
+    if value == "-" {
+        if STDIN_TAKEN.swap(true, Ordering::SeqCst) {
+            output::fail(Failure::Params, format!("Only one input can come from stdin ({})", what));
+        }
+        let mut text = String::new();
+        if let Err(e) = io::stdin().read_to_string(&mut text) {
+            output::fail(Failure::Io, format!("Cannot read {} from stdin: {}", what, e));
+        }
+        Some(text)
+    } else if let Some(path) = value.strip_prefix('@') {
+        match fs::read_to_string(path) {
+            Ok(text) => Some(text),
+            Err(e) => output::fail(Failure::Io, format!("Cannot read {} from '{}': {}", what, path, e)),
+        }
+    } else {
+        None
+    }
+}
+
+/// Raw bytes of a file, for payloads that need not be text.
+pub fn file(path: &str, what: &str) -> Vec<u8> {
+    match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => output::fail(Failure::Io, format!("Cannot read {} from '{}': {}", what, path, e)),
+    }
+}
+
+/// A single value such as one share or a private key, trimmed.
+pub fn text(value: String, what: &str) -> String {
+    match read_source(&value, what) {
+        Some(text) => text.trim().to_string(),
+        None => value,
+    }
+}
+
+/// A list such as shares or commitments: the lines of a file or stdin are
+/// joined with `separator`, so "1,2\n3,4" becomes "1,2;3,4" for shares.
+pub fn list(value: String, separator: char, what: &str) -> String {
+    let Some(text) = read_source(&value, what) else {
+        return value;
+    };
+
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.trim_end_matches(separator))
+        .collect::<Vec<&str>>()
+        .join(&separator.to_string())
+}
+
+/// A secret message. Only the final line break of a file or stdin is dropped,
+/// since spaces may be part of the secret. When omitted it is prompted for.
+pub fn secret(value: Option<String>, what: &str) -> String {
+    let Some(value) = value else {
+        return match rpassword::prompt_password(format!("{}: ", what)) {
+            Ok(secret) => secret,
+            Err(e) => output::fail(Failure::Io, format!("Cannot prompt for {}: {}", what, e)),
+        };
+    };
+
+    match read_source(&value, what) {
+        Some(text) => {
+            let text = text.strip_suffix('\n').unwrap_or(&text);
+            text.strip_suffix('\r').unwrap_or(text).to_string()
+        }
+        None => value,
+    }
+}
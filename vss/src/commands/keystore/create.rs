@@ -0,0 +1,18 @@
+use std::path::Path;
+use serde_json::json;
+use crate::keystore::{self, Keystore};
+use crate::commands::output::{self, Failure};
+
+pub fn execute(path: &str) {
+    let passphrase = match keystore::new_passphrase() {
+        Ok(passphrase) => passphrase,
+        Err(e) => output::fail(Failure::Params, format!("Cannot read passphrase: {}", e)),
+    };
+
+    if let Err(e) = Keystore::create(Path::new(path), &passphrase) {
+        output::fail(Failure::Io, format!("Cannot create keystore '{}': {}", path, e));
+    }
+
+    say!("Created empty keystore {}", path);
+    output::emit(json!({ "created": path }));
+}
@@ -0,0 +1,14 @@
+use serde_json::json;
+use crate::commands::output::{self, Failure};
+
+pub fn execute(path: &str, name: String) {
+    let (mut store, passphrase) = super::open(path);
+
+    if store.remove(&name).is_none() {
+        output::fail(Failure::Params, format!("No keystore entry named '{}'", name));
+    }
+    super::save(path, &store, &passphrase);
+
+    say!("Deleted '{}' from {}", name, path);
+    output::emit(json!({ "deleted": name, "keystore": path }));
+}
@@ -0,0 +1,36 @@
+use serde_json::json;
+use crate::crypto::params;
+use crate::commands::output;
+
+pub fn execute(path: &str, name: String) {
+    let (store, _) = super::open(path);
+    let entry = super::lookup(&store, &name);
+
+    say!("Entry: {}", entry.name);
+    if entry.params != params::get_fixed_params() {
+        say!("Warning: entry was stored under different group parameters");
+        let (p, q, g) = &entry.params;
+        say!("  p = {}", p);
+        say!("  q = {}", q);
+        say!("  g = {}", g);
+    }
+    say!();
+
+    say!("Share (CLI format):");
+    say!("{}", super::share_string(&entry));
+
+    if !entry.commitments.is_empty() {
+        say!();
+        say!("Commitments (CLI format):");
+        say!("{}", super::commitments_string(&entry));
+    }
+
+    let (p, q, g) = &entry.params;
+    output::emit(json!({
+        "name": entry.name,
+        "share": { "x": entry.share.0.to_string(), "y": entry.share.1.to_string() },
+        "commitments": output::numbers(&entry.commitments),
+        "params": { "p": p.to_string(), "q": q.to_string(), "g": g.to_string() },
+        "params_match": entry.params == params::get_fixed_params(),
+    }));
+}
@@ -0,0 +1,36 @@
+use num_bigint::BigUint;
+use crate::crypto::{params, feldman};
+use crate::keystore::Entry;
+use serde_json::json;
+use crate::commands::output::{self, Failure};
+use crate::commands::parse;
+
+pub fn execute(path: &str, name: String, share: String, commitments: Option<String>) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let (x, y) = parse::share(&share);
+
+    let commitment_list: Vec<BigUint> = match commitments {
+        Some(commitments) => parse::numbers(&commitments, "commitment"),
+        None => Vec::new(),
+    };
+
+    if !commitment_list.is_empty() && !feldman::verify_share(&x, &y, &commitment_list, &g, &p, &q) {
+        output::fail(Failure::Invalid, "Share does not match the commitments, not importing");
+    }
+
+    let (mut store, passphrase) = super::open(path);
+    let entry = Entry {
+        name: name.clone(),
+        share: (x, y),
+        commitments: commitment_list,
+        params: (p, q, g),
+    };
+    if let Err(e) = store.insert(entry) {
+        output::fail(Failure::Params, e);
+    }
+    super::save(path, &store, &passphrase);
+
+    say!("Imported '{}' into {}", name, path);
+    output::emit(json!({ "imported": name, "keystore": path }));
+}
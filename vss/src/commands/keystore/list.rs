@@ -0,0 +1,30 @@
+use serde_json::json;
+use crate::commands::output;
+
+pub fn execute(path: &str) {
+    let (store, _) = super::open(path);
+
+    say!("Keystore: {}", path);
+    say!("Entries: {}", store.entries.len());
+    for entry in &store.entries {
+        say!(
+            "  {} (x={}, {} commitments)",
+            entry.name,
+            entry.share.0,
+            entry.commitments.len()
+        );
+    }
+
+    output::emit(json!({
+        "keystore": path,
+        "entries": store
+            .entries
+            .iter()
+            .map(|entry| json!({
+                "name": entry.name,
+                "x": entry.share.0.to_string(),
+                "commitments": entry.commitments.len(),
+            }))
+            .collect::<Vec<_>>(),
+    }));
+}
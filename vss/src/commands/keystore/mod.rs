@@ -0,0 +1,121 @@
+mod create;
+mod import;
+mod list;
+mod export;
+mod delete;
+
+use std::io;
+use std::path::Path;
+use crate::cli::KeystoreCommand;
+use crate::keystore::{self, Entry, Keystore};
+use super::input;
+use super::output::{self, Failure};
+
+pub fn execute(path: String, cmd: KeystoreCommand) {
+    match cmd {
+        KeystoreCommand::Create => {
+            create::execute(&path);
+        }
+
+        KeystoreCommand::Import { name, share, commitments } => {
+            let share = input::text(share, "share");
+            let commitments = commitments.map(|commitments| input::list(commitments, ',', "commitments"));
+            import::execute(&path, name, share, commitments);
+        }
+
+        KeystoreCommand::List => {
+            list::execute(&path);
+        }
+
+        KeystoreCommand::Export { name } => {
+            export::execute(&path, name);
+        }
+
+        KeystoreCommand::Delete { name } => {
+            delete::execute(&path, name);
+        }
+    }
+}
+
+/// Prompts for the passphrase and decrypts the keystore, exiting on failure.
+fn open(path: &str) -> (Keystore, String) {
+    let passphrase = match keystore::passphrase() {
+        Ok(passphrase) => passphrase,
+        Err(e) => output::fail(Failure::Io, format!("Cannot read passphrase: {}", e)),
+    };
+
+    match Keystore::load(Path::new(path), &passphrase) {
+        Ok(store) => (store, passphrase),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => output::fail(
+            Failure::Io,
+            format!("No keystore at '{}'; run `keystore create` first", path),
+        ),
+        Err(e) => output::fail(Failure::Io, format!("Cannot open keystore '{}': {}", path, e)),
+    }
+}
+
+fn save(path: &str, store: &Keystore, passphrase: &str) {
+    if let Err(e) = store.save(Path::new(path), passphrase) {
+        output::fail(Failure::Io, format!("Cannot write keystore '{}': {}", path, e));
+    }
+}
+
+fn lookup(store: &Keystore, name: &str) -> Entry {
+    match store.get(name) {
+        Some(entry) => entry.clone(),
+        None => output::fail(Failure::Params, format!("No keystore entry named '{}'", name)),
+    }
+}
+
+fn share_string(entry: &Entry) -> String {
+    format!("{},{}", entry.share.0, entry.share.1)
+}
+
+fn commitments_string(entry: &Entry) -> String {
+    entry
+        .commitments
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Resolves `verify-share` inputs: either raw `--share`/`--commitments`, or a
+/// keystore `--entry` whose stored commitments are used unless overridden.
+pub fn share_args(
+    share: Option<String>,
+    commitments: Option<String>,
+    entry: Option<String>,
+    path: &str,
+) -> (String, String) {
+    let (share, stored_commitments) = match (share, entry) {
+        (Some(share), None) => (share, None),
+        (None, Some(name)) => {
+            let (store, _) = open(path);
+            let entry = lookup(&store, &name);
+            (share_string(&entry), Some(commitments_string(&entry)))
+        }
+        _ => output::fail(Failure::Params, "Provide exactly one of --share or --entry"),
+    };
+
+    match commitments.or(stored_commitments.filter(|c| !c.is_empty())) {
+        Some(commitments) => (share, commitments),
+        None => output::fail(Failure::Params, "No commitments given and none stored with the entry"),
+    }
+}
+
+/// Resolves `reconstruct` inputs from raw `--shares` or comma-separated `--entries`.
+pub fn shares_arg(shares: Option<String>, entries: Option<String>, path: &str) -> String {
+    match (shares, entries) {
+        (Some(shares), None) => shares,
+        (None, Some(names)) => {
+            let (store, _) = open(path);
+            names
+                .split(',')
+                .map(|name| share_string(&lookup(&store, name.trim())))
+                .collect::<Vec<String>>()
+                .join(";")
+        }
+        _ => output::fail(Failure::Params, "Provide exactly one of --shares or --entries"),
+    }
+}
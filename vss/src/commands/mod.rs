@@ -1,26 +1,137 @@
+#[macro_use]
+mod output;
+mod parse;
+mod input;
+
 mod split;
 mod verify_secret;
 mod verify_share;
 mod reconstruct;
+mod encrypt;
+mod partial_decrypt;
+mod decrypt;
+mod pvss;
+mod public_keys;
+mod audit_public_key;
+mod robust_split;
+mod serve;
+mod coordinate;
+mod keystore;
+mod recover_share;
+mod recover;
+mod enroll;
+mod holders;
+
+use crate::cli::{Command, Format};
+use output::Failure;
 
-use crate::cli::Command;
+pub fn execute(cmd: Command, format: Format) {
+    output::set_format(format);
 
-pub fn execute(cmd: Command) {
     match cmd {
-        Command::Split { secret, n, k } => {
-            split::execute(secret, n, k);
+        Command::Split { secret, n, k, indices, ids_file, hybrid: None, transcript, .. } => {
+            let plan = holders::plan(n, indices, ids_file);
+            split::execute(input::secret(secret, "Secret"), plan, k, transcript);
+        }
+
+        Command::Split { secret, n, k, indices, ids_file, hybrid: Some(payload), file, transcript } => {
+            let plan = holders::plan(n, indices, ids_file);
+            let plaintext = match file {
+                Some(path) => input::file(&path, "file"),
+                None => input::secret(secret, "Secret").into_bytes(),
+            };
+            split::execute_hybrid(plaintext, plan, k, payload, transcript);
         }
         
         Command::VerifySecret { secret, commitments } => {
-            verify_secret::execute(secret, commitments);
+            let secret = input::secret(secret, "Secret");
+            verify_secret::execute(secret, input::list(commitments, ',', "commitments"));
         }
         
-        Command::VerifyShare { share, commitments, verbose } => {
+        Command::VerifyShare { share, commitments, verbose, entry, keystore, transcript } => {
+            let transcript = transcript.map(|path| holders::load(&path));
+            let share = share.map(|share| input::list(share, ';', "shares"));
+            let commitments = commitments
+                .map(|commitments| input::list(commitments, ',', "commitments"))
+                .or_else(|| transcript.as_ref().map(holders::commitments_string));
+            let (mut share, commitments) = keystore::share_args(share, commitments, entry, &keystore);
+            if let Some(transcript) = &transcript {
+                share = holders::resolve_shares(&share, transcript);
+            }
             verify_share::execute(share, commitments, verbose);
         }
         
-        Command::Reconstruct { shares } => {
-            reconstruct::execute(shares);
+        Command::Reconstruct { shares, entries, keystore, verbose, payload, out, transcript } => {
+            let shares = shares.map(|shares| input::list(shares, ';', "shares"));
+            let mut shares = keystore::shares_arg(shares, entries, &keystore);
+            if let Some(path) = transcript {
+                let transcript = holders::load(&path);
+                shares = holders::resolve_shares(&shares, &transcript);
+                let k = transcript.commitments.len();
+                if shares.split(';').count() < k {
+                    output::fail(Failure::Params, format!("The transcript needs at least k = {} shares", k));
+                }
+            }
+            reconstruct::execute(shares, verbose, payload, out);
+        }
+
+        Command::Encrypt { message, commitments } => {
+            let message = input::secret(message, "Message");
+            encrypt::execute(message, input::list(commitments, ',', "commitments"));
+        }
+
+        Command::PartialDecrypt { share, ciphertext } => {
+            partial_decrypt::execute(input::text(share, "share"), ciphertext);
+        }
+
+        Command::Decrypt { ciphertext, commitments, partials } => {
+            decrypt::execute(ciphertext, input::list(commitments, ',', "commitments"), partials);
+        }
+
+        Command::PublicKeys { commitments, indices } => {
+            public_keys::execute(input::list(commitments, ',', "commitments"), indices);
+        }
+
+        Command::AuditPublicKey { public_keys, k, claimed } => {
+            audit_public_key::execute(public_keys, k, claimed);
+        }
+
+        Command::RobustSplit { secret, n, k, equivocate, silent } => {
+            robust_split::execute(input::secret(secret, "Secret"), n, k, equivocate, silent);
+        }
+
+        Command::Serve { listen, share, commitments, token } => {
+            let commitments = input::list(commitments, ',', "commitments");
+            serve::execute(listen, input::text(share, "share"), commitments, token);
+        }
+
+        Command::Coordinate { holders, k, token, commitments } => {
+            let commitments = commitments.map(|commitments| input::list(commitments, ',', "commitments"));
+            coordinate::execute(holders, k, token, commitments);
+        }
+
+        Command::RecoverShare { shares, index, commitments, corrupt } => {
+            let shares = input::list(shares, ';', "shares");
+            let commitments = input::list(commitments, ',', "commitments");
+            recover_share::execute(shares, index, commitments, corrupt);
+        }
+
+        Command::Enroll { shares, index, id, commitments, transcript } => {
+            let shares = input::list(shares, ';', "shares");
+            let commitments = commitments.map(|commitments| input::list(commitments, ',', "commitments"));
+            enroll::execute(shares, index, id, commitments, transcript);
+        }
+
+        Command::Recover { cmd } => {
+            recover::execute(cmd);
+        }
+
+        Command::Keystore { path, cmd } => {
+            keystore::execute(path, cmd);
+        }
+
+        Command::Pvss { cmd } => {
+            pvss::execute(cmd);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,129 @@
+//! How commands report results.
+//!
+//! In text mode commands print human-readable lines with `say!`. In JSON mode
+//! those lines are suppressed and each command prints exactly one JSON object
+//! with `emit`. Errors go through `fail`, which picks the exit code:
+//!
+//! | code | meaning                                               |
+//! |------|-------------------------------------------------------|
+//! | 0    | success, everything verified                          |
+//! | 1    | a share, secret, proof or key failed verification     |
+//! | 2    | bad command line (reported by clap)                   |
+//! | 3    | an input could not be parsed                          |
+//! | 4    | inputs are well-formed but inconsistent (n/k, sizes)  |
+//! | 5    | I/O, network or keystore failure                      |
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+use serde_json::{Value, json};
+use crate::cli::Format;
+use crate::crypto::encoding;
+
+static FORMAT: OnceLock<Format> = OnceLock::new();
+
+pub fn set_format(format: Format) {
+    let _ = FORMAT.set(format);
+}
+
+pub fn is_json() -> bool {
+    matches!(FORMAT.get(), Some(Format::Json))
+}
+
+/// `println!` that only prints in text mode.
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::commands::output::is_json() {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Invalid,
+    Parse,
+    Params,
+    Io,
+}
+
+impl Failure {
+    pub fn code(self) -> i32 {
+        match self {
+            Failure::Invalid => 1,
+            Failure::Parse => 3,
+            Failure::Params => 4,
+            Failure::Io => 5,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Failure::Invalid => "invalid",
+            Failure::Parse => "parse",
+            Failure::Params => "params",
+            Failure::Io => "io",
+        }
+    }
+}
+
+/// Prints the JSON result. Does nothing in text mode.
+pub fn emit(value: Value) {
+    if is_json() {
+        println!("{}", serde_json::to_string_pretty(&value).expect("JSON values always serialize"));
+    }
+}
+
+/// Reports an error and exits with the matching code.
+pub fn fail(kind: Failure, message: impl Display) -> ! {
+    fail_with(kind, message, json!({}))
+}
+
+/// Like `fail`, but in JSON mode also includes the partial results gathered so far.
+pub fn fail_with(kind: Failure, message: impl Display, mut results: Value) -> ! {
+    if is_json() {
+        results["error"] = json!(kind.name());
+        results["message"] = json!(message.to_string());
+        emit(results);
+    } else {
+        eprintln!("Error: {}", message);
+    }
+    std::process::exit(kind.code());
+}
+
+/// Exits with the "invalid" code after a verification failed; the result
+/// itself has already been printed.
+pub fn exit_if_invalid(all_valid: bool) {
+    if !all_valid {
+        std::process::exit(Failure::Invalid.code());
+    }
+}
+
+/// The recovered field element, and the secret it decodes to as hex and
+/// (when valid) UTF-8. Both are null when it does not decode.
+pub fn encodings(value: &num_bigint::BigUint) -> Value {
+    let bytes = encoding::decode(value);
+    json!({
+        "decimal": value.to_string(),
+        "hex": bytes.as_ref().map(hex::encode),
+        "utf8": bytes.and_then(|bytes| String::from_utf8(bytes).ok()),
+    })
+}
+
+/// Prints a recovered secret as a number and as text or hex. A value that does
+/// not decode means wrong or too few shares, and exits with the "invalid" code.
+pub fn say_secret(label: &str, value: &num_bigint::BigUint) {
+    say!("{} (number): {}", label, value);
+    match encoding::decode(value).map(String::from_utf8) {
+        Some(Ok(text)) => say!("{} (string): {}", label, text),
+        Some(Err(e)) => say!("{} (hex): {}", label, hex::encode(e.into_bytes())),
+        None => fail_with(
+            Failure::Invalid,
+            format!("{} is not an encoded secret - wrong or too few shares?", label),
+            json!({ "valid": false, "number": value.to_string() }),
+        ),
+    }
+}
+
+pub fn numbers(values: &[num_bigint::BigUint]) -> Value {
+    Value::from(values.iter().map(|v| v.to_string()).collect::<Vec<String>>())
+}
@@ -0,0 +1,55 @@
+//! Parsers for the CLI text formats. Any malformed input exits with the parse
+//! error code.
+
+use num_bigint::BigUint;
+use crate::crypto::elgamal::Ciphertext;
+use crate::crypto::encoding;
+use super::output::{self, Failure};
+
+pub fn number(s: &str, what: &str) -> BigUint {
+    BigUint::parse_bytes(s.trim().as_bytes(), 10)
+        .unwrap_or_else(|| output::fail(Failure::Parse, format!("Failed to parse {}: '{}'", what, s.trim())))
+}
+
+/// Comma-separated numbers, e.g. commitments "c0,c1,c2".
+pub fn numbers(list: &str, what: &str) -> Vec<BigUint> {
+    list.split(',').map(|s| number(s, what)).collect()
+}
+
+/// Exactly `count` comma-separated numbers, e.g. a share "x,y".
+pub fn tuple(s: &str, count: usize, format: &str) -> Vec<BigUint> {
+    let parts: Vec<&str> = s.trim().split(',').collect();
+    if parts.len() != count {
+        output::fail(
+            Failure::Parse,
+            format!("Each value must be in format '{}'\nGot: '{}'", format, s.trim()),
+        );
+    }
+    parts.iter().map(|part| number(part, format)).collect()
+}
+
+pub fn share(s: &str) -> (BigUint, BigUint) {
+    let values = tuple(s, 2, "x,y");
+    (values[0].clone(), values[1].clone())
+}
+
+/// Semicolon-separated shares "x1,y1;x2,y2".
+pub fn shares(list: &str) -> Vec<(BigUint, BigUint)> {
+    list.split(';').map(share).collect()
+}
+
+/// Encodes a secret below `bound` (normally q), refusing secrets that do not fit.
+pub fn secret(secret: &str, what: &str, bound: &BigUint) -> BigUint {
+    match encoding::encode(secret.as_bytes(), bound) {
+        Ok(value) => value,
+        Err(e) => output::fail(
+            Failure::Params,
+            format!("{} is too long: {}\n  - Use split --hybrid for longer secrets", what, e),
+        ),
+    }
+}
+
+pub fn ciphertext(s: &str) -> Ciphertext {
+    let values = tuple(s, 2, "c1,c2");
+    Ciphertext { c1: values[0].clone(), c2: values[1].clone() }
+}
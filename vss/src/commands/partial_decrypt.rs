@@ -0,0 +1,39 @@
+use serde_json::json;
+use crate::crypto::{params, threshold};
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(share: String, ciphertext: String) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let (x, y) = parse::share(&share);
+    let ciphertext = parse::ciphertext(&ciphertext);
+
+    say!("Partial Decrypt Mode");
+    say!("Share: x={}, y={}", x, y);
+    say!();
+
+    let Some(partial) = threshold::partial_decrypt(&ciphertext, &x, &y, &g, &p, &q) else {
+        output::fail(Failure::Invalid, "c1 is not in the order-q subgroup; refusing to use the share on it");
+    };
+
+    say!("d = c1^y mod p = {}", partial.d);
+    say!("Proof challenge = {}", partial.proof.challenge);
+    say!("Proof response = {}", partial.proof.response);
+    say!();
+
+    say!("Partial decryption (CLI format):");
+    say!(
+        "{},{},{},{}",
+        partial.x, partial.d, partial.proof.challenge, partial.proof.response
+    );
+
+    output::emit(json!({
+        "x": partial.x.to_string(),
+        "d": partial.d.to_string(),
+        "proof": {
+            "challenge": partial.proof.challenge.to_string(),
+            "response": partial.proof.response.to_string(),
+        },
+    }));
+}
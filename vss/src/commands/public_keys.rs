@@ -0,0 +1,78 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde_json::json;
+use crate::crypto::{params, feldman};
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(commitments: String, indices: String) {
+    let (p, q, _g) = params::get_fixed_params();
+
+    let commitment_list = parse::numbers(&commitments, "commitment");
+    if !feldman::commitments_in_subgroup(&commitment_list, &p, &q) {
+        output::fail(Failure::Invalid, "The commitments are not all in the order-q subgroup");
+    }
+
+    let index_list = parse_indices(&indices);
+
+    say!("Public Keys Mode");
+    say!("Commitments: {}", commitment_list.len());
+    say!("Indices: {}", index_list.len());
+    say!();
+
+    let keys = feldman::verification_keys(&commitment_list, &index_list, &p, &q);
+
+    for (x, key) in index_list.iter().zip(keys.iter()) {
+        say!("g^f({}) = {}", x, key);
+    }
+    say!();
+
+    let keys_str: Vec<String> = index_list
+        .iter()
+        .zip(keys.iter())
+        .map(|(x, key)| format!("{},{}", x, key))
+        .collect();
+    say!("Public keys (CLI format):");
+    say!("{}", keys_str.join(";"));
+
+    output::emit(json!({
+        "public_keys": index_list
+            .iter()
+            .zip(keys.iter())
+            .map(|(x, key)| json!({ "x": x.to_string(), "key": key.to_string() }))
+            .collect::<Vec<_>>(),
+    }));
+}
+
+/// Longest "a..b" range accepted, to keep a typo from computing millions of keys.
+const MAX_RANGE: u64 = 100_000;
+
+/// Accepts an inclusive range "1..5" or a list "1,3,7". Index 0 is the secret's
+/// position, not a holder, and is refused.
+fn parse_indices(indices: &str) -> Vec<BigUint> {
+    let list = if let Some((start, end)) = indices.split_once("..") {
+        let start = parse_bound(start);
+        let end = parse_bound(end);
+        if start > end {
+            output::fail(Failure::Params, format!("Empty index range {}..{}", start, end));
+        }
+        if end - start >= MAX_RANGE {
+            output::fail(Failure::Params, format!("Index ranges are limited to {} indices", MAX_RANGE));
+        }
+        (start..=end).map(BigUint::from).collect()
+    } else {
+        parse::numbers(indices, "index")
+    };
+
+    if list.iter().any(|x| x.is_zero()) {
+        output::fail(Failure::Params, "Index 0 is the secret, not a holder");
+    }
+    list
+}
+
+fn parse_bound(bound: &str) -> u64 {
+    bound
+        .trim()
+        .parse()
+        .unwrap_or_else(|_| output::fail(Failure::Parse, format!("Failed to parse index range bound: '{}'", bound.trim())))
+}
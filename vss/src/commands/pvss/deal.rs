@@ -0,0 +1,72 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+use crate::crypto::{params, elgamal, feldman, pvss};
+use serde_json::json;
+use crate::commands::output::{self, Failure};
+use crate::commands::parse;
+
+pub fn execute(secret: String, k: usize, public_keys: String) {
+    let public_key_list = super::parse_public_keys(&public_keys);
+    let n = public_key_list.len();
+
+    if k > n || k == 0 {
+        output::fail(
+            Failure::Params,
+            format!("Invalid k value\n  - k must be <= number of public keys ({})\n  - k must be > 0", n),
+        );
+    }
+
+    let (p, q, g) = params::get_fixed_params();
+    let big_g = pvss::second_generator(&p);
+    let mut rng = thread_rng();
+
+    let secret_num = parse::secret(&secret, "Secret", &q);
+    let encoded = elgamal::encode(&secret_num, &p, &q).expect("encoded secrets are below q");
+
+    say!("PVSS Deal Mode");
+    say!("Secret: {}", secret);
+    say!("n={}, k={}", n, k);
+    say!();
+
+    let coefficients: Vec<BigUint> = (0..k).map(|_| rng.gen_biguint_below(&q)).collect();
+    let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+    let encrypted = pvss::deal(&coefficients, &public_key_list, &g, &p, &q);
+    let masked = pvss::mask(&encoded, &big_g, &coefficients[0], &p);
+
+    let commitments_str: Vec<String> = commitments
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    say!("Commitments (CLI format):");
+    say!("{}", commitments_str.join(","));
+    say!();
+
+    let shares_str: Vec<String> = encrypted
+        .iter()
+        .map(|share| format!("{},{},{},{}", share.x, share.y, share.proof.challenge, share.proof.response))
+        .collect();
+    say!("Encrypted shares (CLI format):");
+    say!("{}", shares_str.join(";"));
+    say!();
+
+    say!("Masked secret U = m * G^s (CLI format):");
+    say!("{}", masked);
+
+    output::emit(json!({
+        "n": n,
+        "k": k,
+        "commitments": output::numbers(&commitments),
+        "encrypted_shares": encrypted
+            .iter()
+            .map(|share| json!({
+                "x": share.x.to_string(),
+                "y": share.y.to_string(),
+                "proof": {
+                    "challenge": share.proof.challenge.to_string(),
+                    "response": share.proof.response.to_string(),
+                },
+            }))
+            .collect::<Vec<_>>(),
+        "masked_secret": masked.to_string(),
+    }));
+}
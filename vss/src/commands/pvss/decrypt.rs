@@ -0,0 +1,40 @@
+use crate::crypto::{params, pvss};
+use serde_json::json;
+use crate::commands::output::{self, Failure};
+use crate::commands::parse;
+
+pub fn execute(private_key: String, encrypted_share: String) {
+    let (p, q, _g) = params::get_fixed_params();
+    let big_g = pvss::second_generator(&p);
+
+    let sk = parse::number(&private_key, "private key");
+    let share_list = super::parse_encrypted_shares(&encrypted_share);
+    if share_list.len() != 1 {
+        output::fail(Failure::Params, "Provide exactly one encrypted share");
+    }
+    let share = &share_list[0];
+
+    say!("PVSS Decrypt Mode");
+    say!("Share: x={}", share.x);
+    say!();
+
+    let decrypted = pvss::decrypt_share(share, &sk, &big_g, &p, &q);
+
+    say!("S = Y^(1/sk) mod p = {}", decrypted.s);
+    say!();
+
+    say!("Decrypted share (CLI format):");
+    say!(
+        "{},{},{},{}",
+        decrypted.x, decrypted.s, decrypted.proof.challenge, decrypted.proof.response
+    );
+
+    output::emit(json!({
+        "x": decrypted.x.to_string(),
+        "s": decrypted.s.to_string(),
+        "proof": {
+            "challenge": decrypted.proof.challenge.to_string(),
+            "response": decrypted.proof.response.to_string(),
+        },
+    }));
+}
@@ -0,0 +1,18 @@
+use crate::crypto::{params, pvss};
+use serde_json::json;
+use crate::commands::output;
+
+pub fn execute() {
+    let (p, q, _g) = params::get_fixed_params();
+    let big_g = pvss::second_generator(&p);
+
+    let (sk, pk) = pvss::keygen(&big_g, &p, &q);
+
+    say!("PVSS Keygen Mode");
+    say!("G = {}", big_g);
+    say!();
+    say!("Private key (keep this secret): {}", sk);
+    say!("Public key (give this to the dealer): {}", pk);
+
+    output::emit(json!({ "private_key": sk.to_string(), "public_key": pk.to_string() }));
+}
@@ -0,0 +1,85 @@
+mod keygen;
+mod deal;
+mod verify;
+mod decrypt;
+mod reconstruct;
+
+use num_bigint::BigUint;
+use crate::cli::PvssCommand;
+use crate::crypto::{dleq, params};
+use crate::crypto::pvss::{DecryptedShare, EncryptedShare};
+use super::output::{self, Failure};
+use super::{input, parse};
+
+pub fn execute(cmd: PvssCommand) {
+    match cmd {
+        PvssCommand::Keygen => {
+            keygen::execute();
+        }
+
+        PvssCommand::Deal { secret, k, public_keys } => {
+            deal::execute(input::secret(secret, "Secret"), k, public_keys);
+        }
+
+        PvssCommand::Verify { commitments, public_keys, encrypted_shares } => {
+            verify::execute(input::list(commitments, ',', "commitments"), public_keys, encrypted_shares);
+        }
+
+        PvssCommand::Decrypt { private_key, encrypted_share } => {
+            decrypt::execute(input::secret(private_key, "Private key").trim().to_string(), encrypted_share);
+        }
+
+        PvssCommand::Reconstruct { commitments, public_keys, encrypted_shares, decrypted_shares, masked_secret } => {
+            let commitments = input::list(commitments, ',', "commitments");
+            reconstruct::execute(commitments, public_keys, encrypted_shares, decrypted_shares, masked_secret);
+        }
+    }
+}
+
+/// Both encrypted and decrypted shares travel as 'x,value,challenge,response'.
+fn parse_proven_values(list: &str) -> Vec<(BigUint, BigUint, dleq::Proof)> {
+    list.split(';')
+        .map(|s| {
+            let values = parse::tuple(s, 4, "x,value,challenge,response");
+            let proof = dleq::Proof {
+                challenge: values[2].clone(),
+                response: values[3].clone(),
+            };
+            (values[0].clone(), values[1].clone(), proof)
+        })
+        .collect()
+}
+
+fn parse_encrypted_shares(list: &str) -> Vec<EncryptedShare> {
+    parse_proven_values(list)
+        .into_iter()
+        .map(|(x, y, proof)| EncryptedShare { x, y, proof })
+        .collect()
+}
+
+fn parse_decrypted_shares(list: &str) -> Vec<DecryptedShare> {
+    parse_proven_values(list)
+        .into_iter()
+        .map(|(x, s, proof)| DecryptedShare { x, s, proof })
+        .collect()
+}
+
+/// Holder public keys, in holder order. A key outside the order-q subgroup is
+/// refused, since it would serve as a DLEQ base.
+fn parse_public_keys(list: &str) -> Vec<BigUint> {
+    let (p, q, _g) = params::get_fixed_params();
+    let public_keys = parse::numbers(list, "public key");
+    if let Some(i) = public_keys.iter().position(|pk| !params::in_subgroup(pk, &p, &q)) {
+        output::fail(Failure::Invalid, format!("Public key {} is not in the order-q subgroup", i + 1));
+    }
+    public_keys
+}
+
+/// Public key of the holder at x (holders are numbered from 1 in key order).
+fn public_key_for<'a>(public_keys: &'a [BigUint], x: &BigUint) -> &'a BigUint {
+    let index = usize::try_from(x.clone()).unwrap_or(0);
+    if index == 0 || index > public_keys.len() {
+        output::fail(Failure::Params, format!("No public key for share x={}", x));
+    }
+    &public_keys[index - 1]
+}
@@ -0,0 +1,75 @@
+use crate::crypto::{params, elgamal, pvss};
+use serde_json::json;
+use crate::commands::output::{self, Failure};
+use crate::commands::parse;
+
+pub fn execute(
+    commitments: String,
+    public_keys: String,
+    encrypted_shares: String,
+    decrypted_shares: String,
+    masked_secret: String,
+) {
+    let (p, q, g) = params::get_fixed_params();
+    let big_g = pvss::second_generator(&p);
+
+    let commitment_list = parse::numbers(&commitments, "commitment");
+    let k = commitment_list.len();
+    let public_key_list = super::parse_public_keys(&public_keys);
+    let encrypted_list = super::parse_encrypted_shares(&encrypted_shares);
+    let decrypted_list = super::parse_decrypted_shares(&decrypted_shares);
+    let masked = parse::number(&masked_secret, "masked secret");
+
+    say!("PVSS Reconstruct Mode");
+    say!("Decrypted shares provided: {}", decrypted_list.len());
+    say!("Threshold k = {}", k);
+    say!();
+
+    let mut valid_shares: Vec<pvss::DecryptedShare> = Vec::new();
+    let mut results = Vec::new();
+    for decrypted in &decrypted_list {
+        let encrypted = encrypted_list.iter().find(|share| share.x == decrypted.x);
+        let public_key = super::public_key_for(&public_key_list, &decrypted.x);
+
+        let is_valid = encrypted.is_some_and(|encrypted| {
+            pvss::verify_encrypted_share(encrypted, public_key, &commitment_list, &g, &p, &q)
+                && pvss::verify_decrypted_share(encrypted, decrypted, public_key, &big_g, &p, &q)
+        });
+        results.push(json!({ "x": decrypted.x.to_string(), "valid": is_valid }));
+        if is_valid {
+            say!("Share x={}: VALID", decrypted.x);
+            if !valid_shares.iter().any(|v| v.x == decrypted.x) {
+                valid_shares.push(decrypted.clone());
+            }
+        } else {
+            say!("Share x={}: INVALID - proof of correct decryption failed, ignoring", decrypted.x);
+        }
+    }
+    say!();
+
+    if valid_shares.len() < k {
+        let kind = if results.len() > valid_shares.len() { Failure::Invalid } else { Failure::Params };
+        output::fail_with(
+            kind,
+            format!("Not enough valid decrypted shares\n  - Need {}, got {}", k, valid_shares.len()),
+            json!({ "shares": results, "valid": false }),
+        );
+    }
+    valid_shares.truncate(k);
+
+    let big_g_s = match pvss::reconstruct(&valid_shares, &p, &q) {
+        Some(value) => value,
+        None => output::fail(Failure::Params, "Decrypted shares must have distinct x coordinates"),
+    };
+    let secret_num = elgamal::decode(&pvss::unmask(&masked, &big_g_s, &p), &p, &q);
+
+    say!("G^s = {}", big_g_s);
+    output::say_secret("Secret", &secret_num);
+
+    output::emit(json!({
+        "shares": results,
+        "valid": true,
+        "big_g_s": big_g_s.to_string(),
+        "secret": output::encodings(&secret_num),
+    }));
+}
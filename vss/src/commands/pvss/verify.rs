@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use num_bigint::BigUint;
+use crate::crypto::{params, pvss};
+use serde_json::json;
+use crate::commands::{output, parse};
+
+pub fn execute(commitments: String, public_keys: String, encrypted_shares: String) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let commitment_list = parse::numbers(&commitments, "commitment");
+    let public_key_list = super::parse_public_keys(&public_keys);
+    let share_list = super::parse_encrypted_shares(&encrypted_shares);
+
+    say!("PVSS Verify Mode");
+    say!("Encrypted shares to verify: {}", share_list.len());
+    say!("Commitments: {}", commitment_list.len());
+    say!();
+
+    let mut all_valid = true;
+    let mut results = Vec::new();
+    for share in &share_list {
+        let public_key = super::public_key_for(&public_key_list, &share.x);
+        let is_valid = pvss::verify_encrypted_share(share, public_key, &commitment_list, &g, &p, &q);
+        results.push(json!({ "x": share.x.to_string(), "valid": is_valid }));
+        if is_valid {
+            say!("Share x={}: VALID", share.x);
+        } else {
+            say!("Share x={}: INVALID - does not match the commitments", share.x);
+            all_valid = false;
+        }
+    }
+    say!();
+
+    // Count holders, not entries: a repeated x covers only one holder.
+    let holders: HashSet<&BigUint> = share_list.iter().map(|share| &share.x).collect();
+    let complete = holders.len() == public_key_list.len();
+    if !complete {
+        say!("Only {} of {} holders have an encrypted share!", holders.len(), public_key_list.len());
+    } else if all_valid {
+        say!("The dealer distributed a valid share to every holder.");
+    } else {
+        say!("Some encrypted shares failed verification!");
+    }
+
+    output::emit(json!({
+        "valid": all_valid && complete,
+        "complete": complete,
+        "shares": results,
+    }));
+    output::exit_if_invalid(all_valid && complete);
+}
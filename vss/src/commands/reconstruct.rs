@@ -1,50 +1,101 @@
+use std::fs;
 use num_bigint::BigUint;
-use crate::crypto::params;
-use crate::math::lagrange;
+use serde_json::json;
+use crate::crypto::{hybrid, params};
+use crate::math::lagrange::{self, InterpolationTrace};
+use super::output::{self, Failure};
+use super::{input, parse};
 
-pub fn execute(shares: String) {
+pub fn execute(shares: String, verbose: bool, payload: Option<String>, out: Option<String>) {
     let (_p, q, _g) = params::get_fixed_params();
 
-    let share_list: Vec<(BigUint, BigUint)> = shares
-        .split(';')
-        .map(|s| {
-            let parts: Vec<&str> = s.trim().split(',').collect();
-            if parts.len() != 2 {
-                eprintln!("Error: Each share must be in format 'x,y'");
-                std::process::exit(1);
-            }
-            
-            let x = BigUint::parse_bytes(parts[0].as_bytes(), 10)
-                .expect("Failed to parse x coordinate");
-            let y = BigUint::parse_bytes(parts[1].as_bytes(), 10)
-                .expect("Failed to parse y coordinate");
-            
-            (x, y) //hm yaha tuple return krenge
-        })
-        .collect();
+    let share_list = parse::shares(&shares);
     
-    println!("Reconstruct Mode");
-    println!("Shares provided: {}", share_list.len());
-    println!();
+    say!("Reconstruct Mode");
+    say!("Shares provided: {}", share_list.len());
+    say!();
 
-    println!("Using q = {}", q);
+    say!("Using q = {}", q);
     for (i, (x, y)) in share_list.iter().enumerate() {
-        println!("Share {}: x={}, y={}", i + 1, x, y);
+        say!("Share {}: x={}, y={}", i + 1, x, y);
     }
-    println!();
+    say!();
 
-    let reconstructed = lagrange::interpolate(&share_list, &q);
+    let result = if verbose {
+        lagrange::interpolate_traced(&share_list, &q).map(|(secret, trace)| {
+            print_trace(&trace);
+            secret
+        })
+    } else {
+        lagrange::interpolate(&share_list, &q)
+    };
+    let reconstructed = match result {
+        Ok(secret) => secret,
+        Err(e) => output::fail(Failure::Params, format!("Cannot reconstruct: {}", e)),
+    };
     
-    println!("Secret (number): {}", reconstructed);
+    if let Some(payload_path) = payload {
+        return decrypt_payload(&reconstructed, share_list.len(), &payload_path, out);
+    }
+
+    output::say_secret("Secret", &reconstructed);
+
+    output::emit(json!({
+        "shares_used": share_list.len(),
+        "secret": output::encodings(&reconstructed),
+    }));
+}
+
+/// Hybrid mode: the reconstructed value is the data key for the payload.
+fn decrypt_payload(data_key: &BigUint, shares_used: usize, payload_path: &str, out: Option<String>) {
+    let (_p, q, _g) = params::get_fixed_params();
+    let payload = input::file(payload_path, "payload");
+
+    let plaintext = match hybrid::open(data_key, &payload, &q) {
+        Some(plaintext) => plaintext,
+        None => output::fail(
+            Failure::Invalid,
+            format!("Payload '{}' does not decrypt under the reconstructed key (wrong shares or tampered payload)", payload_path),
+        ),
+    };
+
+    say!("Data key reconstructed; payload decrypted ({} bytes).", plaintext.len());
 
-    let secret_bytes = reconstructed.to_bytes_be();
-    match String::from_utf8(secret_bytes.clone()) {
-        Ok(secret_string) => {
-            println!("Secret (string): {}", secret_string);
+    if let Some(out_path) = &out {
+        if let Err(e) = fs::write(out_path, &plaintext) {
+            output::fail(Failure::Io, format!("Cannot write '{}': {}", out_path, e));
         }
-        Err(_) => {
-            println!("Secret (string): [Invalid UTF-8]");
-            println!("  The reconstructed secret is not valid UTF-8 text.");
+        say!("Written to {}", out_path);
+    } else {
+        match std::str::from_utf8(&plaintext) {
+            Ok(text) => say!("Secret (string): {}", text),
+            Err(_) => say!("Secret (hex): {}", hex::encode(&plaintext)),
         }
     }
-}
\ No newline at end of file
+
+    let inline = out.is_none();
+    output::emit(json!({
+        "shares_used": shares_used,
+        "payload_bytes": plaintext.len(),
+        "out": out,
+        "secret": inline.then(|| json!({
+            "hex": hex::encode(&plaintext),
+            "utf8": std::str::from_utf8(&plaintext).ok(),
+        })),
+    }));
+}
+
+fn print_trace(trace: &InterpolationTrace) {
+    say!("Lagrange interpolation steps:");
+    for (i, step) in trace.terms.iter().enumerate() {
+        say!();
+        say!("Processing share {}: x={}, y={}", i + 1, step.x, step.y);
+        say!("  numerator = {}", step.numerator);
+        say!("  denominator = {}", step.denominator);
+        say!("  denominator_inv = {}", step.denominator_inv);
+        say!("  lagrange_coeff = {}", step.lagrange_coeff);
+        say!("  term = {}", step.term);
+        say!("  running secret = {}", step.running_secret);
+    }
+    say!();
+}
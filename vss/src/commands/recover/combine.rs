@@ -0,0 +1,48 @@
+use serde_json::json;
+use crate::crypto::{params, recovery};
+use crate::commands::output::{self, Failure};
+use crate::commands::{parse, recover_share};
+
+pub fn execute(index: String, helpers: String, piece_commitments: String, sums: String, commitments: String) {
+    let (_p, q, _g) = params::get_fixed_params();
+
+    let target = parse::number(&index, "index");
+    let helper_list = parse::numbers(&helpers, "helper index");
+    let piece_commitments: Vec<_> = piece_commitments
+        .split(';')
+        .map(|row| parse::numbers(row, "piece commitment"))
+        .collect();
+    let sum_list = parse::numbers(&sums, "sum");
+    let commitment_list = parse::numbers(&commitments, "commitment");
+
+    let count = helper_list.len();
+    if piece_commitments.len() != count || piece_commitments.iter().any(|row| row.len() != count) {
+        output::fail(
+            Failure::Params,
+            format!("Expected {} rows of {} piece commitments, one row per helper", count, count),
+        );
+    }
+    if sum_list.len() != count {
+        output::fail(Failure::Params, format!("Expected {} sums, one per helper, got {}", count, sum_list.len()));
+    }
+
+    say!("Recover Combine Mode");
+    say!("Lost index: {}", target);
+
+    recover_share::check_helpers(&helper_list, &target, commitment_list.len());
+
+    let run = match recovery::assemble(&target, &helper_list, piece_commitments, sum_list, &q) {
+        Some(run) => run,
+        None => output::fail(Failure::Params, "Helpers must have distinct x coordinates"),
+    };
+
+    let (y, results) = recover_share::conclude(&run, &commitment_list);
+    let valid = y.is_some();
+
+    output::emit(json!({
+        "valid": valid,
+        "helpers": results,
+        "share": y.map(|y| json!({ "x": target.to_string(), "y": y.to_string() })),
+    }));
+    output::exit_if_invalid(valid);
+}
@@ -0,0 +1,48 @@
+use serde_json::json;
+use crate::crypto::{params, recovery};
+use crate::commands::output::{self, Failure};
+use crate::commands::parse;
+
+pub fn execute(share: String, helpers: String, index: String) {
+    let (p, q, g) = params::get_fixed_params();
+
+    let share = parse::share(&share);
+    let helper_list = parse::numbers(&helpers, "helper index");
+    let target = parse::number(&index, "index");
+
+    if helper_list.contains(&target) {
+        output::fail(Failure::Params, format!("Helper x={} already holds a share at this index", target));
+    }
+
+    let contribution = match recovery::contribute(&share, &helper_list, &target, &g, &p, &q) {
+        Some(contribution) => contribution,
+        None => output::fail(
+            Failure::Params,
+            format!("Helpers must have distinct x coordinates and include this share's x={}", share.0),
+        ),
+    };
+
+    say!("Recover Contribute Mode");
+    say!("Helper x={} toward index {}", share.0, target);
+    say!();
+
+    say!("Pieces (send each one privately to its helper):");
+    for (x, piece) in helper_list.iter().zip(contribution.pieces.iter()) {
+        say!("To x={}: {}", x, piece);
+    }
+    say!();
+
+    say!("Piece commitments (publish):");
+    say!("{}", contribution.commitments.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(","));
+
+    output::emit(json!({
+        "x": share.0.to_string(),
+        "index": target.to_string(),
+        "pieces": helper_list
+            .iter()
+            .zip(contribution.pieces.iter())
+            .map(|(x, piece)| json!({ "to": x.to_string(), "piece": piece.to_string() }))
+            .collect::<Vec<_>>(),
+        "piece_commitments": output::numbers(&contribution.commitments),
+    }));
+}
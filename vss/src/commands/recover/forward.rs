@@ -0,0 +1,20 @@
+use serde_json::json;
+use crate::crypto::{params, recovery};
+use crate::commands::output;
+use crate::commands::parse;
+
+pub fn execute(pieces: String) {
+    let (_p, q, _g) = params::get_fixed_params();
+    let piece_list = parse::numbers(&pieces, "piece");
+
+    say!("Recover Forward Mode");
+    say!("Pieces received: {}", piece_list.len());
+    say!();
+
+    let sum = recovery::forward(&piece_list, &q);
+
+    say!("Sum (send to the recovering holder):");
+    say!("{}", sum);
+
+    output::emit(json!({ "sum": sum.to_string() }));
+}
@@ -0,0 +1,27 @@
+//! The recovery protocol split into the steps each party runs on its own
+//! machine. `recover-share` and `enroll` run all of them in one process.
+
+mod contribute;
+mod forward;
+mod combine;
+
+use crate::cli::RecoverCommand;
+use super::input;
+
+pub fn execute(cmd: RecoverCommand) {
+    match cmd {
+        RecoverCommand::Contribute { share, helpers, index } => {
+            contribute::execute(input::text(share, "share"), helpers, index);
+        }
+
+        RecoverCommand::Forward { pieces } => {
+            forward::execute(input::list(pieces, ',', "pieces"));
+        }
+
+        RecoverCommand::Combine { index, helpers, piece_commitments, sums, commitments } => {
+            let piece_commitments = input::list(piece_commitments, ';', "piece commitments");
+            let commitments = input::list(commitments, ',', "commitments");
+            combine::execute(index, helpers, piece_commitments, sums, commitments);
+        }
+    }
+}
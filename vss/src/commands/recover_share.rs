@@ -0,0 +1,115 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde_json::{Value, json};
+use crate::crypto::{params, feldman, recovery};
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(shares: String, index: String, commitments: String, corrupt: Option<String>) {
+    let helpers = parse::shares(&shares);
+    let target = parse::number(&index, "index");
+    let commitment_list = parse::numbers(&commitments, "commitment");
+    let corrupt = corrupt.map(|x| parse::number(&x, "corrupt helper index"));
+
+    say!("Recover Share Mode");
+    say!("Lost index: {}", target);
+
+    let (y, results) = run(&helpers, &target, &commitment_list, corrupt.as_ref());
+    let valid = y.is_some();
+
+    output::emit(json!({
+        "valid": valid,
+        "helpers": results,
+        "share": y.map(|y| json!({ "x": target.to_string(), "y": y.to_string() })),
+    }));
+    output::exit_if_invalid(valid);
+}
+
+/// Runs the recovery protocol for f(target) among `helpers` in one process and
+/// prints each helper's status. Returns the share only if it verifies against
+/// the commitments.
+pub(super) fn run(
+    helpers: &[(BigUint, BigUint)],
+    target: &BigUint,
+    commitments: &[BigUint],
+    corrupt: Option<&BigUint>,
+) -> (Option<BigUint>, Vec<Value>) {
+    let (p, q, g) = params::get_fixed_params();
+    let xs: Vec<BigUint> = helpers.iter().map(|(x, _)| x.clone()).collect();
+    check_helpers(&xs, target, commitments.len());
+
+    let mut run = match recovery::start(helpers, target, &g, &p, &q) {
+        Some(run) => run,
+        None => output::fail(Failure::Params, "Helpers must have distinct x coordinates"),
+    };
+
+    // A corrupt helper forwards a sum that is off by one.
+    if let Some(bad) = corrupt {
+        match run.helpers.iter().position(|x| x == bad) {
+            Some(j) => run.sums[j] = (&run.sums[j] + 1u32) % &q,
+            None => output::fail(Failure::Params, format!("No helper with x={} to corrupt", bad)),
+        }
+    }
+
+    say!("Round 1: each helper splits λ_i · f(x_i) into {} blinded pieces", run.helpers.len());
+    say!("Round 2: each helper forwards the sum of the pieces it received");
+    say!();
+
+    conclude(&run, commitments)
+}
+
+/// Refuses a helper set that cannot recover f(target).
+pub(super) fn check_helpers(helpers: &[BigUint], target: &BigUint, k: usize) {
+    let (_p, q, _g) = params::get_fixed_params();
+
+    if helpers.len() < k {
+        output::fail(
+            Failure::Params,
+            format!("Need at least k = {} helpers, got {}", k, helpers.len()),
+        );
+    }
+    if (target % &q).is_zero() {
+        output::fail(Failure::Params, "The index must not be zero");
+    }
+    if helpers.contains(target) {
+        output::fail(Failure::Params, format!("Helper x={} already holds a share at this index", target));
+    }
+
+    say!("Helpers: {}", helpers.len());
+    say!("Threshold k = {}", k);
+    say!();
+}
+
+/// Checks every helper's messages, prints who cheated, and returns the share
+/// only if it verifies against the commitments.
+pub(super) fn conclude(run: &recovery::Recovery, commitments: &[BigUint]) -> (Option<BigUint>, Vec<Value>) {
+    let (p, q, g) = params::get_fixed_params();
+    let target = &run.target;
+
+    let pieces_ok = recovery::check_pieces(run, commitments, &p, &q);
+    let sums_ok = recovery::check_sums(run, &g, &p);
+
+    let mut results = Vec::new();
+    for ((x, &pieces_valid), &sum_valid) in run.helpers.iter().zip(pieces_ok.iter()).zip(sums_ok.iter()) {
+        let status = match (pieces_valid, sum_valid) {
+            (true, true) => "OK",
+            (false, _) => "CHEATED - pieces do not add up to its weighted share",
+            (true, false) => "CHEATED - forwarded sum does not match the pieces it received",
+        };
+        say!("Helper x={}: {}", x, status);
+        results.push(json!({ "x": x.to_string(), "pieces_valid": pieces_valid, "sum_valid": sum_valid }));
+    }
+    say!();
+
+    let y = recovery::finish(run, &q);
+    if feldman::verify_share(target, &y, commitments, &g, &p, &q) {
+        say!("✓ Share verifies against the commitments.");
+        say!();
+        say!("Share (CLI format):");
+        say!("{},{}", target, y);
+        (Some(y), results)
+    } else {
+        say!("✗ Share does NOT verify against the commitments; discard it.");
+        (None, results)
+    }
+}
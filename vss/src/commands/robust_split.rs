@@ -0,0 +1,124 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+use crate::broadcast::bracha;
+use crate::crypto::{params, polynomial, feldman};
+use serde_json::json;
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(secret: String, n: usize, k: usize, equivocate: usize, silent: Option<String>) {
+    if k > n || k == 0 || n == 0 {
+        output::fail(
+            Failure::Params,
+            "Invalid n or k values\n  - k must be <= n\n  - Both k and n must be > 0",
+        );
+    }
+    if equivocate > n {
+        output::fail(Failure::Params, "Cannot equivocate to more than n holders");
+    }
+
+    let silent_list: Vec<usize> = match silent {
+        Some(silent) => silent
+            .split(',')
+            .map(|s| {
+                let x: usize = s.trim().parse().unwrap_or_else(|_| {
+                    output::fail(Failure::Parse, format!("Failed to parse silent holder index: '{}'", s.trim()))
+                });
+                if x == 0 || x > n {
+                    output::fail(Failure::Params, "Silent holder index must be between 1 and n");
+                }
+                x - 1
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut rng = thread_rng();
+    let (p, q, g) = params::get_fixed_params();
+    let secret_num = parse::secret(&secret, "Secret", &q);
+    let f = bracha::max_faulty(n);
+
+    say!("Robust Split Mode");
+    say!("Secret: {}", secret);
+    say!("n={}, k={}", n, k);
+    say!("Tolerated faulty holders f = {}", f);
+    say!("Echo quorum = {}, ready quorum = {}", bracha::echo_quorum(n, f), 2 * f + 1);
+    say!();
+
+    let mut coefficients = vec![secret_num];
+    for _ in 1..k {
+        coefficients.push(rng.gen_biguint_below(&q));
+    }
+
+    // A cheating dealer hands the last `equivocate` holders shares of a different
+    // polynomial, with matching commitments, so their local check still passes.
+    let mut forged_coefficients = coefficients.clone();
+    forged_coefficients[k - 1] = (&forged_coefficients[k - 1] + 1u32) % &q;
+
+    let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+    let forged_commitments = feldman::generate_commitments(&forged_coefficients, &g, &p);
+
+    let mut sends = Vec::new();
+    let mut shares = Vec::new();
+    for i in 1..=n {
+        let x = BigUint::from(i as u64);
+        if i > n - equivocate {
+            shares.push((x.clone(), polynomial::evaluate(&forged_coefficients, &x, &q)));
+            sends.push(forged_commitments.clone());
+        } else {
+            shares.push((x.clone(), polynomial::evaluate(&coefficients, &x, &q)));
+            sends.push(commitments.clone());
+        }
+    }
+
+    if equivocate > 0 {
+        say!("Dealer equivocates: holders {}..={} get different commitments", n - equivocate + 1, n);
+        say!();
+    }
+
+    let holders = bracha::run(&sends, f, &silent_list);
+
+    let mut accepted = 0;
+    let mut results = Vec::new();
+    for (holder, (x, y)) in holders.iter().zip(shares.iter()) {
+        let received = holder.received().expect("dealer sends to every holder");
+
+        let status = match holder.delivered() {
+            None => "REJECTED - no commitment list reached the ready quorum",
+            Some(delivered) if delivered != received => {
+                "REJECTED - peers agreed on different commitments than the dealer sent"
+            }
+            Some(delivered) if !feldman::verify_share(x, y, delivered, &g, &p, &q) => {
+                "REJECTED - share does not match the agreed commitments"
+            }
+            Some(_) => {
+                accepted += 1;
+                "ACCEPTED"
+            }
+        };
+        say!("Holder {}: {}", x, status);
+        results.push(json!({ "x": x.to_string(), "status": status }));
+    }
+    say!();
+
+    say!("Dealing accepted by {} of {} holders.", accepted, n);
+    if let Some(delivered) = holders.iter().find_map(|holder| holder.delivered()) {
+        let commitments_str: Vec<String> = delivered
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        say!();
+        say!("Agreed commitments (CLI format):");
+        say!("{}", commitments_str.join(","));
+    }
+
+    let agreed = holders.iter().find_map(|holder| holder.delivered());
+    output::emit(json!({
+        "n": n,
+        "k": k,
+        "f": f,
+        "accepted": accepted,
+        "holders": results,
+        "commitments": agreed.map(|commitments| output::numbers(commitments)),
+    }));
+}
@@ -0,0 +1,30 @@
+use std::net::TcpListener;
+use crate::crypto::{feldman, params};
+use crate::net::server::{self, Holder};
+use serde_json::json;
+use super::output::{self, Failure};
+use super::parse;
+
+pub fn execute(listen: String, share: String, commitments: String, token: String) {
+    let (p, q, g) = params::get_fixed_params();
+    let (x, y) = parse::share(&share);
+    let commitments = parse::numbers(&commitments, "commitment");
+
+    if !feldman::verify_share(&x, &y, &commitments, &g, &p, &q) {
+        output::fail(Failure::Invalid, format!("Share x={} does not match the commitments", x));
+    }
+
+    let listener = match TcpListener::bind(&listen) {
+        Ok(listener) => listener,
+        Err(e) => output::fail(Failure::Io, format!("Cannot listen on {}: {}", listen, e)),
+    };
+
+    say!("Serve Mode");
+    say!("Holding share x={}", x);
+    say!("Listening on {}", listen);
+    output::emit(json!({ "x": x.to_string(), "listening": listen }));
+
+    if let Err(e) = server::serve(listener, Holder { x, y, commitments, token }) {
+        output::fail(Failure::Io, e);
+    }
+}
@@ -1,71 +1,138 @@
+use std::fs;
+use std::path::Path;
 use num_bigint::{BigUint, RandBigInt};
 use rand::thread_rng;
-use crate::crypto::{params, polynomial, feldman};
+use serde_json::{Value, json};
+use crate::crypto::{params, polynomial, feldman, hybrid};
+use crate::transcript::Transcript;
+use super::output::{self, Failure};
+use super::parse;
 
-pub fn execute(secret: String, n: usize, k: usize) {
-    if k > n || k == 0 || n == 0 {
-        eprintln!("Error: Invalid n or k values");
-        eprintln!("  - k must be <= n");
-        eprintln!("  - Both k and n must be > 0");
-        std::process::exit(1);
+pub fn execute(secret: String, plan: Transcript, k: usize, transcript: Option<String>) {
+    let n = plan.holders.len();
+    check_n_k(n, k);
+
+    let (_p, q, _g) = params::get_fixed_params();
+    let secret_num = parse::secret(&secret, "Secret", &q);
+
+    say!("Split Mode");
+    say!("Secret: {}", secret);
+    say!("n={}, k={}", n, k);
+    say!();
+
+    output::emit(share(&secret_num, plan, k, transcript));
+}
+
+/// Shares a fresh data key and writes the plaintext, sealed under it, to `payload_path`.
+pub fn execute_hybrid(plaintext: Vec<u8>, plan: Transcript, k: usize, payload_path: String, transcript: Option<String>) {
+    let n = plan.holders.len();
+    check_n_k(n, k);
+
+    let (_p, q, _g) = params::get_fixed_params();
+    let data_key = hybrid::data_key(&q);
+    let payload = hybrid::seal(&data_key, &plaintext, &q);
+
+    if let Err(e) = fs::write(&payload_path, &payload) {
+        output::fail(Failure::Io, format!("Cannot write payload '{}': {}", payload_path, e));
     }
 
-    let mut rng = thread_rng();
+    say!("Hybrid Split Mode");
+    say!("Secret: {} bytes, encrypted to {}", plaintext.len(), payload_path);
+    say!("n={}, k={}", n, k);
+    say!("The shares below hide the data key; keep the payload file with them.");
+    say!();
 
-    let secret_num = BigUint::from_bytes_be(secret.as_bytes());
+    let mut result = share(&data_key, plan, k, transcript);
+    result["payload"] = json!(payload_path);
+    result["payload_bytes"] = json!(payload.len());
+    output::emit(result);
+}
 
-    let (p, q, g) = params::get_fixed_params();
+fn check_n_k(n: usize, k: usize) {
+    if k > n || k == 0 || n == 0 {
+        output::fail(
+            Failure::Params,
+            "Invalid n or k values\n  - k must be <= n\n  - Both k and n must be > 0",
+        );
+    }
+}
 
-    println!("Split Mode");
-    println!("Secret: {}", secret);
-    println!("n={}, k={}", n, k);
-    println!();
+/// Deals a share to every holder in `plan`, prints the shares and commitments,
+/// and returns the JSON result.
+fn share(secret_num: &BigUint, mut plan: Transcript, k: usize, transcript_path: Option<String>) -> Value {
+    let n = plan.holders.len();
+    let mut rng = thread_rng();
+    let (p, q, g) = params::get_fixed_params();
 
-    let mut coefficients = vec![secret_num.clone() % &q];
+    let mut coefficients = vec![secret_num.clone()];
     for _ in 1..k {
         
-        coefficients.push(rng.gen_biguint(q.bits() as u64) % &q);
+        coefficients.push(rng.gen_biguint(q.bits()) % &q);
     }
 
     let commitments = feldman::generate_commitments(&coefficients, &g, &p);
 
-    let mut shares = Vec::new();
-    for i in 1..=n {
-        let x = BigUint::from(i as u64);
-        let y = polynomial::evaluate(&coefficients, &x, &q);
-        shares.push((x, y));
-    }
+    let shares: Vec<(BigUint, BigUint)> = plan
+        .holders
+        .iter()
+        .map(|holder| (holder.x.clone(), polynomial::evaluate(&coefficients, &holder.x, &q)))
+        .collect();
 
-    println!("Public Parameters:");
-    println!("p = {}", p);
-    println!("q = {}", q);
-    println!("g = {}", g);
-    println!();
+    say!("Public Parameters:");
+    say!("p = {}", p);
+    say!("q = {}", q);
+    say!("g = {}", g);
+    say!();
     
-    println!("Commitments:");
+    say!("Commitments:");
     for (i, commitment) in commitments.iter().enumerate() {
-        println!("C[{}] = {}", i, commitment);
+        say!("C[{}] = {}", i, commitment);
     }
-    println!();
+    say!();
 
     let commitments_str: Vec<String> = commitments
         .iter()
         .map(|c| c.to_string())
         .collect();
-    println!("Commitments (CLI format):");
-    println!("{}", commitments_str.join(","));
-    println!();
+    say!("Commitments (CLI format):");
+    say!("{}", commitments_str.join(","));
+    say!();
     
-    println!("Shares:");
-    for (i, (x, y)) in shares.iter().enumerate() {
-        println!("Share {}: {},{}", i + 1, x, y);
+    say!("Shares:");
+    for (i, ((x, y), holder)) in shares.iter().zip(plan.holders.iter()).enumerate() {
+        match &holder.id {
+            Some(id) => say!("Share {} ({}): {},{}", i + 1, id, x, y),
+            None => say!("Share {}: {},{}", i + 1, x, y),
+        }
     }
-    println!();
+    say!();
 
     let shares_str: Vec<String> = shares
         .iter()
         .map(|(x, y)| format!("{},{}", x, y))
         .collect();
-    println!("Shares (CLI format):");
-    println!("{}", shares_str.join(";"));
-}
\ No newline at end of file
+    say!("Shares (CLI format):");
+    say!("{}", shares_str.join(";"));
+
+    plan.commitments = commitments.clone();
+    if let Some(path) = &transcript_path {
+        if let Err(e) = plan.save(Path::new(path)) {
+            output::fail(Failure::Io, format!("Cannot write transcript '{}': {}", path, e));
+        }
+        say!();
+        say!("Transcript written to {}", path);
+    }
+
+    json!({
+        "n": n,
+        "k": k,
+        "params": { "p": p.to_string(), "q": q.to_string(), "g": g.to_string() },
+        "commitments": output::numbers(&commitments),
+        "shares": shares
+            .iter()
+            .zip(plan.holders.iter())
+            .map(|((x, y), holder)| json!({ "x": x.to_string(), "y": y.to_string(), "id": holder.id }))
+            .collect::<Vec<_>>(),
+        "transcript": transcript_path,
+    })
+}
@@ -1,37 +1,39 @@
-use num_bigint::BigUint;
+use serde_json::json;
 use crate::crypto::params;
+use super::{output, parse};
 
 pub fn execute(secret: String, commitments: String) {
+    let (p, q, g) = params::get_fixed_params();
 
-    let secret_num = BigUint::from_bytes_be(secret.as_bytes());
+    let secret_num = parse::secret(&secret, "Secret", &q);
 
-    let (p, _q, g) = params::get_fixed_params();
-
-    let commitment_list: Vec<BigUint> = commitments
-        .split(',')
-        .map(|s| {
-            BigUint::parse_bytes(s.trim().as_bytes(), 10)
-                .expect("Failed to parse commitment to BigUint")
-        })
-        .collect();
+    let commitment_list = parse::numbers(&commitments, "commitment");
     
-    println!("Verify Secret Mode");
-    println!("Secret: {}", secret);
-    println!("Secret as number: {}", secret_num);
-    println!();
+    say!("Verify Secret Mode");
+    say!("Secret: {}", secret);
+    say!("Secret as number: {}", secret_num);
+    say!();
     
     let computed_commitment = g.modpow(&secret_num, &p);
     let first_commitment = &commitment_list[0];
     
-    println!("First commitment (C[0]): {}", first_commitment);
-    println!("Computed (g^secret mod p): {}", computed_commitment);
-    println!();
+    say!("First commitment (C[0]): {}", first_commitment);
+    say!("Computed (g^secret mod p): {}", computed_commitment);
+    say!();
 
-    if computed_commitment == *first_commitment {
-        println!("✓ Secret verified successfully!");
-        println!("  The secret matches the commitment.");
+    let is_valid = computed_commitment == *first_commitment;
+    if is_valid {
+        say!("✓ Secret verified successfully!");
+        say!("  The secret matches the commitment.");
     } else {
-        println!("✗ Secret verification failed!");
-        println!("  The secret does NOT match the commitment.");
+        say!("✗ Secret verification failed!");
+        say!("  The secret does NOT match the commitment.");
     }
-}
\ No newline at end of file
+
+    output::emit(json!({
+        "valid": is_valid,
+        "commitment": first_commitment.to_string(),
+        "computed": computed_commitment.to_string(),
+    }));
+    output::exit_if_invalid(is_valid);
+}
@@ -0,0 +1,107 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+use sha2::{Digest, Sha256};
+use super::params::in_subgroup;
+
+/// Chaum–Pedersen proof that log_g1(h1) = log_g2(h2) without revealing the
+/// exponent. Made non-interactive with the Fiat–Shamir heuristic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proof {
+    pub challenge: BigUint,
+    pub response: BigUint,
+}
+
+/// Prover knows x with h1 = g1^x and h2 = g2^x (mod p).
+///
+/// a1 = g1^w, a2 = g2^w for a random w
+/// c  = H(g1, h1, g2, h2, a1, a2) mod q
+/// z  = w - c*x mod q
+pub fn prove(
+    g1: &BigUint,
+    h1: &BigUint,
+    g2: &BigUint,
+    h2: &BigUint,
+    x: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Proof {
+    let mut rng = thread_rng();
+    let w = rng.gen_biguint_below(q);
+
+    let a1 = g1.modpow(&w, p);
+    let a2 = g2.modpow(&w, p);
+    let challenge = hash_to_challenge(&[g1, h1, g2, h2, &a1, &a2], q);
+
+    let cx = (&challenge * x) % q;
+    let response = (w + q - cx) % q;
+
+    Proof { challenge, response }
+}
+
+/// The verifier recomputes the commitments as
+/// a1 = g1^z * h1^c, a2 = g2^z * h2^c (mod p)
+/// and checks that they hash back to the same challenge.
+pub fn verify(
+    g1: &BigUint,
+    h1: &BigUint,
+    g2: &BigUint,
+    h2: &BigUint,
+    proof: &Proof,
+    p: &BigUint,
+    q: &BigUint,
+) -> bool {
+    if proof.challenge >= *q || proof.response >= *q {
+        return false;
+    }
+    if !in_subgroup(h1, p, q) || !in_subgroup(h2, p, q) {
+        return false;
+    }
+
+    let a1 = (g1.modpow(&proof.response, p) * h1.modpow(&proof.challenge, p)) % p;
+    let a2 = (g2.modpow(&proof.response, p) * h2.modpow(&proof.challenge, p)) % p;
+
+    hash_to_challenge(&[g1, h1, g2, h2, &a1, &a2], q) == proof.challenge
+}
+
+fn hash_to_challenge(values: &[&BigUint], q: &BigUint) -> BigUint {
+    let mut hasher = Sha256::new();
+    hasher.update(b"vss-dleq");
+    for value in values {
+        let bytes = value.to_bytes_be();
+        hasher.update((bytes.len() as u64).to_be_bytes());
+        hasher.update(&bytes);
+    }
+    BigUint::from_bytes_be(&hasher.finalize()) % q
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::params;
+
+    #[test]
+    fn test_dleq_roundtrip() {
+        let (p, q, g) = params::get_fixed_params();
+
+        let x = BigUint::from(123456789u64);
+        let g2 = g.modpow(&BigUint::from(987u32), &p);
+        let h1 = g.modpow(&x, &p);
+        let h2 = g2.modpow(&x, &p);
+
+        let proof = prove(&g, &h1, &g2, &h2, &x, &p, &q);
+        assert!(verify(&g, &h1, &g2, &h2, &proof, &p, &q));
+    }
+
+    #[test]
+    fn test_dleq_rejects_different_exponents() {
+        let (p, q, g) = params::get_fixed_params();
+
+        let x = BigUint::from(123456789u64);
+        let g2 = g.modpow(&BigUint::from(987u32), &p);
+        let h1 = g.modpow(&x, &p);
+        let h2 = g2.modpow(&(&x + 1u32), &p);
+
+        let proof = prove(&g, &h1, &g2, &h2, &x, &p, &q);
+        assert!(!verify(&g, &h1, &g2, &h2, &proof, &p, &q));
+    }
+}
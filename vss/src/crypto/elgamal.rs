@@ -0,0 +1,67 @@
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::One;
+use rand::thread_rng;
+use crate::crypto::params;
+
+/// ElGamal ciphertext (c1, c2) = (g^r, m * pk^r) mod p.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ciphertext {
+    pub c1: BigUint,
+    pub c2: BigUint,
+}
+
+/// Maps a message m < q to an element of the order-q subgroup.
+/// m + 1 is in [1, q]; exactly one of (m + 1) and p - (m + 1) is a quadratic residue.
+pub fn encode(m: &BigUint, p: &BigUint, q: &BigUint) -> Option<BigUint> {
+    if m >= q {
+        return None;
+    }
+    let shifted = m + 1u32;
+    if shifted.modpow(q, p).is_one() {
+        Some(shifted)
+    } else {
+        Some(p - shifted)
+    }
+}
+
+/// Inverse of `encode`.
+pub fn decode(element: &BigUint, p: &BigUint, q: &BigUint) -> BigUint {
+    if element <= q {
+        element - 1u32
+    } else {
+        p - element - 1u32
+    }
+}
+
+/// Encrypts an encoded message to the public key pk = g^s (for VSS, pk = C[0]).
+/// None if pk is outside the order-q subgroup.
+pub fn encrypt(message: &BigUint, pk: &BigUint, g: &BigUint, p: &BigUint, q: &BigUint) -> Option<Ciphertext> {
+    if !params::in_subgroup(pk, p, q) {
+        return None;
+    }
+    let mut rng = thread_rng();
+    let r = rng.gen_biguint_range(&BigUint::one(), q);
+
+    let c1 = g.modpow(&r, p);
+    let c2 = (message * pk.modpow(&r, p)) % p;
+
+    Some(Ciphertext { c1, c2 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let (p, q, _g) = params::get_fixed_params();
+
+        for m in [0u32, 1, 2, 3, 1000] {
+            let m = BigUint::from(m);
+            let element = encode(&m, &p, &q).unwrap();
+            assert!(element.modpow(&q, &p).is_one());
+            assert_eq!(decode(&element, &p, &q), m);
+        }
+        assert!(encode(&q, &p, &q).is_none());
+    }
+}
@@ -0,0 +1,103 @@
+//! Secrets as field elements.
+//!
+//! A secret of L bytes becomes the number whose big-endian bytes are `L || secret`,
+//! with L in a single header byte. The header keeps leading zero bytes, which a
+//! plain byte-to-number conversion would drop, and lets `decode` tell a real
+//! secret from a random field element. Secrets whose encoding is not below the
+//! bound are refused rather than reduced, since a reduced secret can never be
+//! recovered.
+
+use std::fmt;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EncodingError {
+    TooLong { len: usize, max: usize },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::TooLong { len, max } => {
+                write!(f, "secret is {} bytes but at most {} bytes fit the field", len, max)
+            }
+        }
+    }
+}
+
+/// Longest secret whose encoding is below `bound` whatever its bytes are.
+pub fn max_len(bound: &BigUint) -> usize {
+    let mut len = 0;
+    while len < u8::MAX as usize && largest_encoding(len + 1) < *bound {
+        len += 1;
+    }
+    len
+}
+
+/// (L + 1) * 256^L - 1: header L followed by L bytes of 0xff.
+fn largest_encoding(len: usize) -> BigUint {
+    (BigUint::from(len + 1) << (8 * len)) - 1u32
+}
+
+pub fn encode(secret: &[u8], bound: &BigUint) -> Result<BigUint, EncodingError> {
+    let too_long = EncodingError::TooLong { len: secret.len(), max: max_len(bound) };
+    if secret.len() > u8::MAX as usize {
+        return Err(too_long);
+    }
+
+    let mut bytes = Vec::with_capacity(secret.len() + 1);
+    bytes.push(secret.len() as u8);
+    bytes.extend_from_slice(secret);
+
+    let value = BigUint::from_bytes_be(&bytes);
+    if value >= *bound {
+        return Err(too_long);
+    }
+    Ok(value)
+}
+
+/// None when the value does not carry a consistent length header, e.g. when it
+/// was reconstructed from the wrong shares.
+pub fn decode(value: &BigUint) -> Option<Vec<u8>> {
+    if value.is_zero() {
+        return Some(Vec::new());
+    }
+
+    let bytes = value.to_bytes_be();
+    let (&len, secret) = bytes.split_first()?;
+    if len as usize != secret.len() {
+        return None;
+    }
+    Some(secret.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::params;
+
+    #[test]
+    fn test_roundtrip_keeps_leading_zeros() {
+        let (_p, q, _g) = params::get_fixed_params();
+
+        for secret in [&b""[..], b"hi", b"\x00\x00key", &[0xff; 15]] {
+            let value = encode(secret, &q).unwrap();
+            assert!(value < q);
+            assert_eq!(decode(&value), Some(secret.to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_too_long_is_refused() {
+        let (_p, q, _g) = params::get_fixed_params();
+        let max = max_len(&q);
+
+        assert!(encode(&vec![0xff; max], &q).is_ok());
+        assert_eq!(
+            encode(&vec![0u8; max + 1], &q),
+            Err(EncodingError::TooLong { len: max + 1, max }),
+        );
+        assert_eq!(decode(&(BigUint::from(7u32) << 16)), None);
+    }
+}
@@ -1,5 +1,6 @@
 use num_bigint::BigUint;
 use num_traits::One;
+use super::params::in_subgroup;
 
 pub fn generate_commitments(coeffs: &[BigUint], g: &BigUint, p: &BigUint) -> Vec<BigUint> {
     coeffs
@@ -13,20 +14,72 @@ pub fn generate_commitments(coeffs: &[BigUint], g: &BigUint, p: &BigUint) -> Vec
 /// Which expands to:
 /// g^y ≡ C[0]^(x^0) * C[1]^(x^1) * C[2]^(x^2) * ... (mod p)
 /// g^y ≡ C[0] * C[1]^x * C[2]^(x^2) * C[3]^(x^3) * ... (mod p)
-
-pub fn verify_share(x: &BigUint,y: &BigUint,commitments: &[BigUint],g: &BigUint,p: &BigUint,_q: &BigUint) -> bool {
+///
+/// Commitments outside the order-q subgroup are rejected, since reducing x^j
+/// mod q is only sound for subgroup elements.
+pub fn verify_share(x: &BigUint,y: &BigUint,commitments: &[BigUint],g: &BigUint,p: &BigUint,q: &BigUint) -> bool {
+    if !commitments_in_subgroup(commitments, p, q) {
+        return false;
+    }
     let left_side = g.modpow(y, p);
+    let right_side = verification_key(commitments, x, p, q);
+    left_side == right_side
+}
 
-    let mut right_side = BigUint::one();
+pub fn commitments_in_subgroup(commitments: &[BigUint], p: &BigUint, q: &BigUint) -> bool {
+    commitments.iter().all(|commitment| in_subgroup(commitment, p, q))
+}
+
+/// Holder x's public verification key g^{f(x)} = ∏ C[j]^(x^j) (mod p).
+/// The commitments live in the order-q subgroup, so x^j is reduced mod q.
+pub fn verification_key(commitments: &[BigUint], x: &BigUint, p: &BigUint, q: &BigUint) -> BigUint {
+    let mut result = BigUint::one();
     let mut x_power = BigUint::one();
-    
+
     for commitment in commitments {
-        let term = commitment.modpow(&x_power, p);
-        right_side = right_side * term;
-        x_power = &x_power * x;
+        result = (result * commitment.modpow(&x_power, p)) % p;
+        x_power = (x_power * x) % q;
     }
-    right_side = right_side % p;
-    left_side == right_side
+    result
+}
+
+/// Verification keys for many indices at once.
+///
+/// Every C[j]^(2^b) is computed a single time and shared by all indices, so each
+/// extra index costs only multiplications instead of fresh exponentiations.
+pub fn verification_keys(commitments: &[BigUint], xs: &[BigUint], p: &BigUint, q: &BigUint) -> Vec<BigUint> {
+    let bits = q.bits();
+
+    let tables: Vec<Vec<BigUint>> = commitments
+        .iter()
+        .map(|commitment| {
+            let mut table = Vec::with_capacity(bits as usize);
+            let mut power = commitment % p;
+            for _ in 0..bits {
+                let next = (&power * &power) % p;
+                table.push(power);
+                power = next;
+            }
+            table
+        })
+        .collect();
+
+    xs.iter()
+        .map(|x| {
+            let mut result = BigUint::one();
+            let mut x_power = BigUint::one();
+
+            for table in &tables {
+                for (b, power) in table.iter().enumerate() {
+                    if x_power.bit(b as u64) {
+                        result = (result * power) % p;
+                    }
+                }
+                x_power = (x_power * x) % q;
+            }
+            result
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -87,4 +140,38 @@ mod tests {
         
         assert!(!verify_share(&x, &y, &commitments, &g, &p, &q));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_verify_share_rejects_commitment_outside_subgroup() {
+        let p = BigUint::from(23u32);
+        let q = BigUint::from(11u32);
+        let g = BigUint::from(2u32);
+
+        // 5 is not a square mod 23, so it has order 22 and is not in the subgroup.
+        // With C[1] = 5 the check g^y = C[0] * C[1]^x would hold for x = 11 and y = 5
+        // if exponents were reduced mod q without the subgroup check.
+        let commitments = vec![g.modpow(&BigUint::from(5u32), &p), BigUint::from(5u32)];
+        assert!(!commitments_in_subgroup(&commitments, &p, &q));
+        assert!(!verify_share(&BigUint::from(11u32), &BigUint::from(5u32), &commitments, &g, &p, &q));
+    }
+
+    #[test]
+    fn test_verification_keys_batch_matches_single() {
+        let p = BigUint::from(23u32);
+        let q = BigUint::from(11u32);
+        let g = BigUint::from(2u32);
+
+        // Polynomial: f(x) = 5 + 3x + 7x^2
+        let coeffs = vec![BigUint::from(5u32), BigUint::from(3u32), BigUint::from(7u32)];
+        let commitments = generate_commitments(&coeffs, &g, &p);
+
+        let xs: Vec<BigUint> = (1u32..=12).map(BigUint::from).collect();
+        let batch = verification_keys(&commitments, &xs, &p, &q);
+
+        for (x, key) in xs.iter().zip(batch.iter()) {
+            assert_eq!(*key, verification_key(&commitments, x, &p, &q));
+            let y = (BigUint::from(5u32) + 3u32 * x + 7u32 * x * x) % &q;
+            assert_eq!(*key, g.modpow(&y, &p));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Hybrid sharing for secrets of any length.
+//!
+//! Only a random data key k < q is Feldman-shared. The payload itself is sealed
+//! with ChaCha20-Poly1305 under SHA-256("vss-hybrid-key" || k), so reconstructing
+//! k from any t shares is enough to decrypt it.
+//!
+//! Payload layout: `MAGIC || nonce (12 bytes) || ciphertext`. The magic is also
+//! the associated data, so a payload from another format or version is rejected.
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::One;
+use rand::{RngCore, thread_rng};
+use sha2::{Digest, Sha256};
+
+const MAGIC: &[u8] = b"vss-hybrid v1\n";
+const NONCE_LEN: usize = 12;
+
+pub fn data_key(q: &BigUint) -> BigUint {
+    thread_rng().gen_biguint_range(&BigUint::one(), q)
+}
+
+/// The key is left-padded to the byte length of q so equal keys always hash alike.
+fn aead_key(data_key: &BigUint, q: &BigUint) -> Key {
+    let width = q.bits().div_ceil(8) as usize;
+    let bytes = data_key.to_bytes_be();
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"vss-hybrid-key");
+    hasher.update(vec![0u8; width.saturating_sub(bytes.len())]);
+    hasher.update(&bytes);
+    hasher.finalize()
+}
+
+pub fn seal(data_key: &BigUint, plaintext: &[u8], q: &BigUint) -> Vec<u8> {
+    let mut nonce = [0u8; NONCE_LEN];
+    thread_rng().fill_bytes(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new(&aead_key(data_key, q));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad: MAGIC })
+        .expect("ChaCha20-Poly1305 accepts any plaintext that fits in memory");
+
+    let mut payload = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(MAGIC);
+    payload.extend_from_slice(&nonce);
+    payload.extend_from_slice(&ciphertext);
+    payload
+}
+
+/// None when the payload is malformed, was tampered with, or the key is wrong.
+pub fn open(data_key: &BigUint, payload: &[u8], q: &BigUint) -> Option<Vec<u8>> {
+    let rest = payload.strip_prefix(MAGIC)?;
+    if rest.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let cipher = ChaCha20Poly1305::new(&aead_key(data_key, q));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad: MAGIC })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::params;
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let (_p, q, _g) = params::get_fixed_params();
+        let key = data_key(&q);
+
+        // Far longer than q, which plain sharing could never hold.
+        let plaintext: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let payload = seal(&key, &plaintext, &q);
+
+        assert_eq!(open(&key, &payload, &q), Some(plaintext));
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_key_and_tampering() {
+        let (_p, q, _g) = params::get_fixed_params();
+        let key = data_key(&q);
+        let mut payload = seal(&key, b"attack at dawn", &q);
+
+        assert_eq!(open(&(&key + 1u32), &payload, &q), None);
+
+        let last = payload.len() - 1;
+        payload[last] ^= 1;
+        assert_eq!(open(&key, &payload, &q), None);
+    }
+}
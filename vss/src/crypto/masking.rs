@@ -0,0 +1,104 @@
+//! Per-request zero-sharing for networked reconstruction.
+//!
+//! A daemon must not answer with anything its share can be read back from:
+//! λ_i is public, so λ_i · y_i alone gives y_i away. Instead, holder i in the set
+//! S adds a mask m_i and the masks of S add up to zero. For each other member j
+//! the pair key is K_ij = VK_j^y_i = g^(y_i y_j), where VK_j comes from the
+//! commitments, so only holders i and j can compute it. With
+//! h_ij = H(K_ij, nonce, S), holder i adds h_ij for every j above it and
+//! subtracts it for every j below, so the masks cancel over S.
+//!
+//! Each mask is bound to the nonce and to the exact set, so answers to
+//! different requests cannot be combined to strip the masks.
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+use crate::crypto::feldman;
+
+/// h_ij for the pair key K_ij, the coordinator's nonce and the set.
+fn pair_mask(pair_key: &BigUint, nonce: &[u8], xs: &[BigUint], q: &BigUint) -> BigUint {
+    let mut sorted: Vec<&BigUint> = xs.iter().collect();
+    sorted.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"vss-reconstruction-mask");
+    hasher.update(pair_key.to_bytes_be());
+    hasher.update(nonce);
+    for x in sorted {
+        hasher.update(x.to_bytes_be());
+        hasher.update([0u8]);
+    }
+    BigUint::from_bytes_be(&hasher.finalize()) % q
+}
+
+/// Holder (x, y)'s mask m_i within the set `xs`, which must contain x. The
+/// masks of every member of `xs` add up to zero mod q.
+pub fn mask(
+    x: &BigUint,
+    y: &BigUint,
+    xs: &[BigUint],
+    nonce: &[u8],
+    commitments: &[BigUint],
+    p: &BigUint,
+    q: &BigUint,
+) -> BigUint {
+    xs.iter()
+        .filter(|other| *other != x)
+        .fold(BigUint::zero(), |acc, other| {
+            let pair_key = feldman::verification_key(commitments, other, p, q).modpow(y, p);
+            let h = pair_mask(&pair_key, nonce, xs, q);
+            if x < other {
+                (acc + h) % q
+            } else {
+                (acc + q - h) % q
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{params, polynomial};
+
+    fn setup() -> (Vec<BigUint>, Vec<(BigUint, BigUint)>) {
+        let (p, q, g) = params::get_fixed_params();
+        let coefficients = vec![BigUint::from(4242u32), BigUint::from(7u32), BigUint::from(99u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let shares = (1..=4u32)
+            .map(|x| {
+                let x = BigUint::from(x);
+                let y = polynomial::evaluate(&coefficients, &x, &q);
+                (x, y)
+            })
+            .collect();
+        (commitments, shares)
+    }
+
+    #[test]
+    fn test_masks_of_a_set_cancel() {
+        let (p, q, _g) = params::get_fixed_params();
+        let (commitments, shares) = setup();
+        let xs: Vec<BigUint> = shares.iter().skip(1).map(|(x, _)| x.clone()).collect();
+
+        let total = shares
+            .iter()
+            .skip(1)
+            .map(|(x, y)| mask(x, y, &xs, b"nonce", &commitments, &p, &q))
+            .fold(BigUint::zero(), |acc, m| (acc + m) % &q);
+        assert!(total.is_zero());
+    }
+
+    #[test]
+    fn test_mask_depends_on_nonce_and_set() {
+        let (p, q, _g) = params::get_fixed_params();
+        let (commitments, shares) = setup();
+        let (x, y) = &shares[0];
+        let xs: Vec<BigUint> = shares.iter().take(3).map(|(x, _)| x.clone()).collect();
+
+        let first = mask(x, y, &xs, b"nonce", &commitments, &p, &q);
+        assert!(!first.is_zero());
+        assert_ne!(first, mask(x, y, &xs, b"other nonce", &commitments, &p, &q));
+        assert_ne!(first, mask(x, y, &xs[..2], b"nonce", &commitments, &p, &q));
+    }
+}
@@ -1,3 +1,10 @@
 pub mod params;
 pub mod polynomial;
-pub mod feldman;
\ No newline at end of file
+pub mod feldman;
+pub mod dleq;
+pub mod elgamal;
+pub mod threshold;
+pub mod pvss;pub mod hybrid;
+pub mod encoding;
+pub mod recovery;
+pub mod masking;
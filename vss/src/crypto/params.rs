@@ -1,12 +1,20 @@
 use num_bigint::BigUint;
+use num_traits::{One, Zero};
 
+/// p = 2q + 1 is a safe prime and p ≡ 7 (mod 8), so g = 2 is a quadratic
+/// residue and generates the subgroup of prime order q.
 pub fn get_fixed_params() -> (BigUint, BigUint, BigUint) {
-    let q = BigUint::parse_bytes(b"170141183460469231731687303715884105727", 10)
+    let q = BigUint::parse_bytes(b"170141183460469231731687303715884098003", 10)
         .expect("Failed to parse q");
     
-    let p = &q * 2 as u32 + 1 as u32;
+    let p = &q * 2u32 + 1u32;
 
-    let g = BigUint::from(2 as u32);
+    let g = BigUint::from(2u32);
     
     (p, q, g)
-}
\ No newline at end of file
+}
+
+/// Whether h is an element of the order-q subgroup of Z_p*.
+pub fn in_subgroup(h: &BigUint, p: &BigUint, q: &BigUint) -> bool {
+    !h.is_zero() && h < p && h.modpow(q, p).is_one()
+}
@@ -7,7 +7,7 @@ pub fn evaluate(coeffs: &[BigUint], x: &BigUint, q: &BigUint) -> BigUint {
     
     for coefficient in coeffs {
         result = (result + (coefficient * &power)) % q;
-        power = power * x; 
+        power *= x;
     }
     
     result
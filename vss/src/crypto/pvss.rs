@@ -0,0 +1,193 @@
+//! Schoenmakers PVSS.
+//!
+//! Holder i has a key pair (sk_i, pk_i = G^sk_i). The dealer publishes the Feldman
+//! commitments C[j] = g^a_j and, for every holder, Y_i = pk_i^f(i) with a proof that
+//! log_g(X_i) = log_pk_i(Y_i), where X_i = g^f(i) is computed from the commitments.
+//! Anyone can therefore check every encrypted share, not only its recipient.
+//!
+//! Holder i decrypts S_i = Y_i^(1/sk_i) = G^f(i) and proves log_G(pk_i) = log_S_i(Y_i).
+//! Any k decrypted shares give G^s = ∏ S_i^λ_i, which is never revealed as s itself.
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::thread_rng;
+use sha2::{Digest, Sha256};
+use crate::crypto::{dleq, feldman, params, polynomial};
+use crate::math::lagrange;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncryptedShare {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub proof: dleq::Proof,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecryptedShare {
+    pub x: BigUint,
+    pub s: BigUint,
+    pub proof: dleq::Proof,
+}
+
+/// Second generator G of the order-q subgroup whose discrete log with respect to g
+/// nobody knows: a hash squared into the quadratic residues.
+pub fn second_generator(p: &BigUint) -> BigUint {
+    let digest = Sha256::digest(b"vss-pvss-generator");
+    let h = BigUint::from_bytes_be(&digest) % p;
+    h.modpow(&BigUint::from(2u32), p)
+}
+
+pub fn keygen(big_g: &BigUint, p: &BigUint, q: &BigUint) -> (BigUint, BigUint) {
+    let mut rng = thread_rng();
+    let sk = rng.gen_biguint_range(&BigUint::one(), q);
+    let pk = big_g.modpow(&sk, p);
+    (sk, pk)
+}
+
+/// Encrypts f(i) to the i-th public key for i = 1..=n.
+pub fn deal(
+    coefficients: &[BigUint],
+    public_keys: &[BigUint],
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Vec<EncryptedShare> {
+    public_keys
+        .iter()
+        .enumerate()
+        .map(|(i, pk)| {
+            let x = BigUint::from(i as u64 + 1);
+            let fx = polynomial::evaluate(coefficients, &x, q);
+            let verification_key = g.modpow(&fx, p);
+            let y = pk.modpow(&fx, p);
+            let proof = dleq::prove(g, &verification_key, pk, &y, &fx, p, q);
+            EncryptedShare { x, y, proof }
+        })
+        .collect()
+}
+
+pub fn verify_encrypted_share(
+    share: &EncryptedShare,
+    public_key: &BigUint,
+    commitments: &[BigUint],
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> bool {
+    if !params::in_subgroup(public_key, p, q) {
+        return false;
+    }
+    let verification_key = feldman::verification_key(commitments, &share.x, p, q);
+    dleq::verify(g, &verification_key, public_key, &share.y, &share.proof, p, q)
+}
+
+pub fn decrypt_share(
+    share: &EncryptedShare,
+    sk: &BigUint,
+    big_g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> DecryptedShare {
+    let sk_inv = sk.modpow(&(q - 2u32), q);
+    let s = share.y.modpow(&sk_inv, p);
+    let pk = big_g.modpow(sk, p);
+    let proof = dleq::prove(big_g, &pk, &s, &share.y, sk, p, q);
+
+    DecryptedShare { x: share.x.clone(), s, proof }
+}
+
+pub fn verify_decrypted_share(
+    encrypted: &EncryptedShare,
+    decrypted: &DecryptedShare,
+    public_key: &BigUint,
+    big_g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> bool {
+    encrypted.x == decrypted.x
+        && params::in_subgroup(public_key, p, q)
+        && dleq::verify(big_g, public_key, &decrypted.s, &encrypted.y, &decrypted.proof, p, q)
+}
+
+/// G^s = ∏ S_i^λ_i (mod p). The decrypted shares must already be verified.
+pub fn reconstruct(shares: &[DecryptedShare], p: &BigUint, q: &BigUint) -> Option<BigUint> {
+    let points: Vec<(BigUint, BigUint)> = shares
+        .iter()
+        .map(|share| (share.x.clone(), share.s.clone()))
+        .collect();
+    lagrange::interpolate_in_exponent(&points, &BigUint::zero(), p, q)
+}
+
+/// Hides an encoded message under the shared value: U = m * G^s (mod p).
+pub fn mask(message: &BigUint, big_g: &BigUint, s: &BigUint, p: &BigUint) -> BigUint {
+    (message * big_g.modpow(s, p)) % p
+}
+
+/// Recovers m = U / G^s (mod p) once G^s has been reconstructed.
+pub fn unmask(masked: &BigUint, big_g_s: &BigUint, p: &BigUint) -> BigUint {
+    (masked * big_g_s.modpow(&(p - 2u32), p)) % p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pvss_roundtrip() {
+        let (p, q, g) = params::get_fixed_params();
+        let big_g = second_generator(&p);
+
+        let keys: Vec<(BigUint, BigUint)> = (0..4).map(|_| keygen(&big_g, &p, &q)).collect();
+        let public_keys: Vec<BigUint> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let coefficients = vec![BigUint::from(1234u32), BigUint::from(56u32), BigUint::from(78u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let encrypted = deal(&coefficients, &public_keys, &g, &p, &q);
+
+        for (share, pk) in encrypted.iter().zip(public_keys.iter()) {
+            assert!(verify_encrypted_share(share, pk, &commitments, &g, &p, &q));
+        }
+
+        let decrypted: Vec<DecryptedShare> = [1, 2, 3]
+            .iter()
+            .map(|&i| {
+                let share = decrypt_share(&encrypted[i], &keys[i].0, &big_g, &p, &q);
+                assert!(verify_decrypted_share(&encrypted[i], &share, &public_keys[i], &big_g, &p, &q));
+                share
+            })
+            .collect();
+
+        let expected = big_g.modpow(&coefficients[0], &p);
+        assert_eq!(reconstruct(&decrypted, &p, &q), Some(expected));
+    }
+
+    #[test]
+    fn test_pvss_detects_bad_encrypted_share() {
+        let (p, q, g) = params::get_fixed_params();
+        let big_g = second_generator(&p);
+
+        let (_, pk) = keygen(&big_g, &p, &q);
+        let coefficients = vec![BigUint::from(1234u32), BigUint::from(56u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+
+        let mut encrypted = deal(&coefficients, std::slice::from_ref(&pk), &g, &p, &q);
+        encrypted[0].y = (&encrypted[0].y * &big_g) % &p;
+
+        assert!(!verify_encrypted_share(&encrypted[0], &pk, &commitments, &g, &p, &q));
+    }
+
+    #[test]
+    fn test_pvss_rejects_public_key_outside_subgroup() {
+        let (p, q, g) = params::get_fixed_params();
+        let big_g = second_generator(&p);
+
+        // -pk has order 2q, so pk^f(i) would leak the parity of f(i).
+        let (_, pk) = keygen(&big_g, &p, &q);
+        let bad_pk = &p - &pk;
+        let coefficients = vec![BigUint::from(1234u32), BigUint::from(56u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+
+        let encrypted = deal(&coefficients, std::slice::from_ref(&bad_pk), &g, &p, &q);
+        assert!(!verify_encrypted_share(&encrypted[0], &bad_pk, &commitments, &g, &p, &q));
+    }
+}
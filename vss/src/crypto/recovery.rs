@@ -0,0 +1,222 @@
+//! Verifiable share recovery.
+//!
+//! A holder who lost f(r) gets it back from k helpers without anyone learning
+//! the secret. Helper i holds (x_i, f(x_i)) and the Lagrange weight λ_i(r), so
+//! f(r) = Σ λ_i(r) f(x_i). Each helper splits its weighted value into k random
+//! pieces δ_ij that sum to it mod q and sends δ_ij to helper j. Helper j forwards
+//! only σ_j = Σ_i δ_ij, which on its own is uniformly random, and the recovering
+//! holder adds the σ_j up.
+//!
+//! Helpers also publish E_ij = g^δ_ij. Anyone can check ∏_j E_ij = VK_i^λ_i with
+//! VK_i = g^f(x_i) from the commitments, and the recovering holder can check
+//! g^σ_j = ∏_i E_ij, so a helper that cheats is caught and named.
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::thread_rng;
+use crate::crypto::feldman;
+use crate::math::lagrange;
+
+/// The public messages of one run: the piece commitments E_ij and the forwarded
+/// sums σ_j. The pieces themselves never leave their sender and recipient.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recovery {
+    pub target: BigUint,
+    pub helpers: Vec<BigUint>,
+    pub weights: Vec<BigUint>,
+    pub piece_commitments: Vec<Vec<BigUint>>,
+    pub sums: Vec<BigUint>,
+}
+
+/// One helper's round-1 output: `pieces[j]` goes privately to helper j and
+/// `commitments` is published.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contribution {
+    pub pieces: Vec<BigUint>,
+    pub commitments: Vec<BigUint>,
+}
+
+/// Splits `value` into `count` random pieces that add up to it mod q.
+fn blind(value: &BigUint, count: usize, q: &BigUint) -> Vec<BigUint> {
+    let mut rng = thread_rng();
+    let mut pieces: Vec<BigUint> = (1..count).map(|_| rng.gen_biguint_below(q)).collect();
+
+    let used = pieces.iter().fold(BigUint::zero(), |acc, piece| (acc + piece) % q);
+    pieces.push((value + q - used) % q);
+    pieces
+}
+
+/// Round 1 for the helper holding `share`. None if its x is not among
+/// `helpers` or the x-coordinates repeat.
+pub fn contribute(
+    share: &(BigUint, BigUint),
+    helpers: &[BigUint],
+    target: &BigUint,
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<Contribution> {
+    let (x, y) = share;
+    let i = helpers.iter().position(|h| h == x)?;
+    let weights = lagrange::coefficients_at(helpers, target, q)?;
+
+    let pieces = blind(&((y * &weights[i]) % q), helpers.len(), q);
+    let commitments = pieces.iter().map(|piece| g.modpow(piece, p)).collect();
+    Some(Contribution { pieces, commitments })
+}
+
+/// Round 2 for one helper: the sum of the pieces it was sent.
+pub fn forward(pieces: &[BigUint], q: &BigUint) -> BigUint {
+    pieces.iter().fold(BigUint::zero(), |acc, piece| (acc + piece) % q)
+}
+
+/// Collects the published messages for checking. `piece_commitments[i]` and
+/// `sums[i]` come from `helpers[i]`. None if the x-coordinates repeat.
+pub fn assemble(
+    target: &BigUint,
+    helpers: &[BigUint],
+    piece_commitments: Vec<Vec<BigUint>>,
+    sums: Vec<BigUint>,
+    q: &BigUint,
+) -> Option<Recovery> {
+    Some(Recovery {
+        target: target.clone(),
+        helpers: helpers.to_vec(),
+        weights: lagrange::coefficients_at(helpers, target, q)?,
+        piece_commitments,
+        sums,
+    })
+}
+
+/// Runs both rounds with honest helpers in one process. None if the
+/// x-coordinates repeat.
+pub fn start(
+    helpers: &[(BigUint, BigUint)],
+    target: &BigUint,
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<Recovery> {
+    let xs: Vec<BigUint> = helpers.iter().map(|(x, _)| x.clone()).collect();
+    let contributions = helpers
+        .iter()
+        .map(|share| contribute(share, &xs, target, g, p, q))
+        .collect::<Option<Vec<Contribution>>>()?;
+
+    let sums = (0..helpers.len())
+        .map(|j| {
+            let received: Vec<BigUint> = contributions.iter().map(|c| c.pieces[j].clone()).collect();
+            forward(&received, q)
+        })
+        .collect();
+
+    let piece_commitments = contributions.into_iter().map(|c| c.commitments).collect();
+    assemble(target, &xs, piece_commitments, sums, q)
+}
+
+/// Per helper i: do its published pieces add up to λ_i · f(x_i) in the exponent?
+pub fn check_pieces(recovery: &Recovery, commitments: &[BigUint], p: &BigUint, q: &BigUint) -> Vec<bool> {
+    let keys = feldman::verification_keys(commitments, &recovery.helpers, p, q);
+
+    keys.iter()
+        .zip(recovery.weights.iter())
+        .zip(recovery.piece_commitments.iter())
+        .map(|((key, weight), row)| {
+            let product = row.iter().fold(BigUint::one(), |acc, e| (acc * e) % p);
+            product == key.modpow(weight, p)
+        })
+        .collect()
+}
+
+/// Per helper j: is the forwarded sum the sum of the pieces it was sent?
+pub fn check_sums(recovery: &Recovery, g: &BigUint, p: &BigUint) -> Vec<bool> {
+    recovery
+        .sums
+        .iter()
+        .enumerate()
+        .map(|(j, sum)| {
+            let expected = recovery
+                .piece_commitments
+                .iter()
+                .fold(BigUint::one(), |acc, row| (acc * &row[j]) % p);
+            g.modpow(sum, p) == expected
+        })
+        .collect()
+}
+
+/// The recovered share f(target) = Σ σ_j (mod q).
+pub fn finish(recovery: &Recovery, q: &BigUint) -> BigUint {
+    recovery.sums.iter().fold(BigUint::zero(), |acc, sum| (acc + sum) % q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{params, polynomial};
+
+    fn setup() -> (Vec<BigUint>, Vec<BigUint>, Vec<(BigUint, BigUint)>) {
+        let (p, q, g) = params::get_fixed_params();
+        let coefficients = vec![BigUint::from(4242u32), BigUint::from(17u32), BigUint::from(99u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let helpers = [1u32, 3, 5]
+            .iter()
+            .map(|&x| {
+                let x = BigUint::from(x);
+                let y = polynomial::evaluate(&coefficients, &x, &q);
+                (x, y)
+            })
+            .collect();
+        (coefficients, commitments, helpers)
+    }
+
+    #[test]
+    fn test_recovers_lost_share() {
+        let (p, q, g) = params::get_fixed_params();
+        let (coefficients, commitments, helpers) = setup();
+        let target = BigUint::from(2u32);
+
+        let recovery = start(&helpers, &target, &g, &p, &q).unwrap();
+        assert!(check_pieces(&recovery, &commitments, &p, &q).iter().all(|&ok| ok));
+        assert!(check_sums(&recovery, &g, &p).iter().all(|&ok| ok));
+
+        let y = finish(&recovery, &q);
+        assert_eq!(y, polynomial::evaluate(&coefficients, &target, &q));
+        assert!(feldman::verify_share(&target, &y, &commitments, &g, &p, &q));
+    }
+
+    #[test]
+    fn test_per_helper_rounds_match_simulation() {
+        let (p, q, g) = params::get_fixed_params();
+        let (coefficients, commitments, helpers) = setup();
+        let target = BigUint::from(2u32);
+        let xs: Vec<BigUint> = helpers.iter().map(|(x, _)| x.clone()).collect();
+
+        let contributions: Vec<Contribution> = helpers
+            .iter()
+            .map(|share| contribute(share, &xs, &target, &g, &p, &q).unwrap())
+            .collect();
+        let sums = (0..xs.len())
+            .map(|j| forward(&contributions.iter().map(|c| c.pieces[j].clone()).collect::<Vec<_>>(), &q))
+            .collect();
+        let piece_commitments = contributions.into_iter().map(|c| c.commitments).collect();
+
+        let recovery = assemble(&target, &xs, piece_commitments, sums, &q).unwrap();
+        assert!(check_pieces(&recovery, &commitments, &p, &q).iter().all(|&ok| ok));
+        assert!(check_sums(&recovery, &g, &p).iter().all(|&ok| ok));
+        assert_eq!(finish(&recovery, &q), polynomial::evaluate(&coefficients, &target, &q));
+    }
+
+    #[test]
+    fn test_cheating_helper_is_named() {
+        let (p, q, g) = params::get_fixed_params();
+        let (_, commitments, helpers) = setup();
+        let target = BigUint::from(2u32);
+
+        let mut recovery = start(&helpers, &target, &g, &p, &q).unwrap();
+        recovery.sums[1] = (&recovery.sums[1] + 1u32) % &q;
+
+        assert_eq!(check_sums(&recovery, &g, &p), vec![true, false, true]);
+        let y = finish(&recovery, &q);
+        assert!(!feldman::verify_share(&target, &y, &commitments, &g, &p, &q));
+    }
+}
@@ -0,0 +1,152 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use crate::crypto::{dleq, feldman, params};
+use crate::crypto::elgamal::Ciphertext;
+use crate::math::lagrange;
+
+/// Holder i's share of the decryption d_i = c1^{f(i)}, together with a proof that
+/// log_g(g^{f(i)}) = log_c1(d_i).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialDecryption {
+    pub x: BigUint,
+    pub d: BigUint,
+    pub proof: dleq::Proof,
+}
+
+/// None if c1 is outside the order-q subgroup: for c1 = -g^a, c1^y would
+/// reveal the parity of the share y.
+pub fn partial_decrypt(
+    ciphertext: &Ciphertext,
+    x: &BigUint,
+    y: &BigUint,
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<PartialDecryption> {
+    if !params::in_subgroup(&ciphertext.c1, p, q) {
+        return None;
+    }
+    let d = ciphertext.c1.modpow(y, p);
+    let verification_key = g.modpow(y, p);
+    let proof = dleq::prove(g, &verification_key, &ciphertext.c1, &d, y, p, q);
+
+    Some(PartialDecryption { x: x.clone(), d, proof })
+}
+
+/// Checks the DLEQ proof against the holder's verification key g^{f(i)},
+/// which anyone can compute from the commitments.
+pub fn verify_partial(
+    ciphertext: &Ciphertext,
+    partial: &PartialDecryption,
+    commitments: &[BigUint],
+    g: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> bool {
+    if !params::in_subgroup(&ciphertext.c1, p, q) {
+        return false;
+    }
+    let verification_key = feldman::verification_key(commitments, &partial.x, p, q);
+    dleq::verify(g, &verification_key, &ciphertext.c1, &partial.d, &partial.proof, p, q)
+}
+
+/// Recovers the encoded message m = c2 / ∏ d_i^{λ_i} (mod p), where λ_i are the
+/// Lagrange coefficients at zero. The partials must already be verified.
+pub fn combine(
+    ciphertext: &Ciphertext,
+    partials: &[PartialDecryption],
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<BigUint> {
+    let points: Vec<(BigUint, BigUint)> = partials
+        .iter()
+        .map(|partial| (partial.x.clone(), partial.d.clone()))
+        .collect();
+    let blinding = lagrange::interpolate_in_exponent(&points, &BigUint::zero(), p, q)?;
+
+    let blinding_inv = blinding.modpow(&(p - 2u32), p);
+    Some((&ciphertext.c2 * blinding_inv) % p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{elgamal, params, polynomial};
+
+    fn deal(
+        coefficients: &[BigUint],
+        n: u64,
+        q: &BigUint,
+    ) -> Vec<(BigUint, BigUint)> {
+        (1..=n)
+            .map(|i| {
+                let x = BigUint::from(i);
+                let y = polynomial::evaluate(coefficients, &x, q);
+                (x, y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_threshold_decrypt() {
+        let (p, q, g) = params::get_fixed_params();
+
+        let coefficients = vec![
+            BigUint::from(424242u32),
+            BigUint::from(17u32),
+            BigUint::from(99u32),
+        ];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let shares = deal(&coefficients, 5, &q);
+
+        let message = elgamal::encode(&BigUint::from(31337u32), &p, &q).unwrap();
+        let ciphertext = elgamal::encrypt(&message, &commitments[0], &g, &p, &q).unwrap();
+
+        let partials: Vec<PartialDecryption> = [&shares[4], &shares[0], &shares[2]]
+            .iter()
+            .map(|(x, y)| partial_decrypt(&ciphertext, x, y, &g, &p, &q).unwrap())
+            .collect();
+
+        for partial in &partials {
+            assert!(verify_partial(&ciphertext, partial, &commitments, &g, &p, &q));
+        }
+        assert_eq!(combine(&ciphertext, &partials, &p, &q), Some(message));
+    }
+
+    #[test]
+    fn test_tampered_partial_is_rejected() {
+        let (p, q, g) = params::get_fixed_params();
+
+        let coefficients = vec![BigUint::from(424242u32), BigUint::from(17u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let shares = deal(&coefficients, 3, &q);
+
+        let message = elgamal::encode(&BigUint::from(7u32), &p, &q).unwrap();
+        let ciphertext = elgamal::encrypt(&message, &commitments[0], &g, &p, &q).unwrap();
+
+        let (x, y) = &shares[1];
+        let mut partial = partial_decrypt(&ciphertext, x, y, &g, &p, &q).unwrap();
+        partial.d = (&partial.d * &g) % &p;
+
+        assert!(!verify_partial(&ciphertext, &partial, &commitments, &g, &p, &q));
+    }
+
+    #[test]
+    fn test_c1_outside_subgroup_is_refused() {
+        let (p, q, g) = params::get_fixed_params();
+
+        let coefficients = vec![BigUint::from(424242u32), BigUint::from(17u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+        let shares = deal(&coefficients, 3, &q);
+
+        let message = elgamal::encode(&BigUint::from(7u32), &p, &q).unwrap();
+        let mut ciphertext = elgamal::encrypt(&message, &commitments[0], &g, &p, &q).unwrap();
+        let (x, y) = &shares[0];
+        let partial = partial_decrypt(&ciphertext, x, y, &g, &p, &q).unwrap();
+
+        // -c1 has order 2q; its powers would leak the parity of y.
+        ciphertext.c1 = &p - &ciphertext.c1;
+        assert!(partial_decrypt(&ciphertext, x, y, &g, &p, &q).is_none());
+        assert!(!verify_partial(&ciphertext, &partial, &commitments, &g, &p, &q));
+    }
+}
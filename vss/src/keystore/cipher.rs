@@ -0,0 +1,55 @@
+use std::io;
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::RngCore;
+
+pub const SALT_LEN: usize = 16;
+pub const NONCE_LEN: usize = 12;
+
+pub struct Sealed {
+    pub salt: [u8; SALT_LEN],
+    pub nonce: [u8; NONCE_LEN],
+    pub ciphertext: Vec<u8>,
+}
+
+/// Argon2id (default parameters) turns the passphrase into a 256-bit key.
+fn derive_key(passphrase: &str, salt: &[u8]) -> io::Result<Key> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| io::Error::other(format!("key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+/// Encrypts with ChaCha20-Poly1305 under a fresh salt and nonce.
+/// `header` is authenticated but not encrypted.
+pub fn seal(passphrase: &str, header: &[u8], plaintext: &[u8]) -> io::Result<Sealed> {
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad: header })
+        .map_err(|_| io::Error::other("encryption failed"))?;
+
+    Ok(Sealed { salt, nonce, ciphertext })
+}
+
+pub fn open(passphrase: &str, header: &[u8], sealed: &Sealed) -> io::Result<Vec<u8>> {
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &sealed.salt)?);
+    cipher
+        .decrypt(
+            Nonce::from_slice(&sealed.nonce),
+            Payload { msg: &sealed.ciphertext, aad: header },
+        )
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "wrong passphrase or corrupted keystore",
+            )
+        })
+}
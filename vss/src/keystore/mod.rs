@@ -0,0 +1,279 @@
+//! Passphrase-protected share storage.
+//!
+//! The keystore file is text:
+//! ```text
+//! vss-keystore v1
+//! salt <hex>
+//! nonce <hex>
+//! <hex ciphertext>
+//! ```
+//! The ciphertext holds one entry per line as `name;x,y;c0,c1,...;p,q,g`
+//! (the commitment field may be empty).
+
+pub mod cipher;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use num_bigint::BigUint;
+use cipher::Sealed;
+
+const HEADER: &str = "vss-keystore v1";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub name: String,
+    pub share: (BigUint, BigUint),
+    pub commitments: Vec<BigUint>,
+    /// Group parameters (p, q, g) the share was created under.
+    pub params: (BigUint, BigUint, BigUint),
+}
+
+#[derive(Default)]
+pub struct Keystore {
+    pub entries: Vec<Entry>,
+}
+
+impl Keystore {
+    /// Writes a new, empty keystore. Refuses to replace an existing file.
+    pub fn create(path: &Path, passphrase: &str) -> io::Result<Self> {
+        if path.exists() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, "keystore already exists"));
+        }
+        let store = Keystore::default();
+        store.save(path, passphrase)?;
+        Ok(store)
+    }
+
+    /// Loads an existing keystore; a missing file is an error, see `create`.
+    pub fn load(path: &Path, passphrase: &str) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("not a vss keystore"));
+        }
+
+        let salt = read_field(lines.next(), "salt ")?;
+        let nonce = read_field(lines.next(), "nonce ")?;
+        let ciphertext = read_field(lines.next(), "")?;
+
+        let sealed = Sealed {
+            salt: salt.try_into().map_err(|_| invalid("bad salt length"))?,
+            nonce: nonce.try_into().map_err(|_| invalid("bad nonce length"))?,
+            ciphertext,
+        };
+        let plaintext = cipher::open(passphrase, HEADER.as_bytes(), &sealed)?;
+        let plaintext = String::from_utf8(plaintext).map_err(|_| invalid("keystore is not UTF-8"))?;
+
+        let entries = plaintext
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_entry)
+            .collect::<io::Result<Vec<Entry>>>()?;
+
+        Ok(Keystore { entries })
+    }
+
+    /// Re-encrypts everything under a fresh salt and nonce and replaces the file.
+    pub fn save(&self, path: &Path, passphrase: &str) -> io::Result<()> {
+        let plaintext: String = self
+            .entries
+            .iter()
+            .map(|entry| format_entry(entry) + "\n")
+            .collect();
+
+        let sealed = cipher::seal(passphrase, HEADER.as_bytes(), plaintext.as_bytes())?;
+        let text = format!(
+            "{}\nsalt {}\nnonce {}\n{}\n",
+            HEADER,
+            hex::encode(sealed.salt),
+            hex::encode(sealed.nonce),
+            hex::encode(&sealed.ciphertext),
+        );
+
+        // Append to the file name: with_extension would give the keystore itself
+        // back for a path that already ends in .tmp.
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, path)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    pub fn insert(&mut self, entry: Entry) -> io::Result<()> {
+        if entry.name.is_empty() || entry.name.contains([';', '\n', '\r']) {
+            return Err(invalid("entry names must be non-empty and contain no ';' or newlines"));
+        }
+        if self.get(&entry.name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("entry '{}' already exists", entry.name),
+            ));
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Entry> {
+        let index = self.entries.iter().position(|entry| entry.name == name)?;
+        Some(self.entries.remove(index))
+    }
+}
+
+/// Reads the passphrase from VSS_KEYSTORE_PASSPHRASE, or prompts without echo.
+pub fn passphrase() -> io::Result<String> {
+    match std::env::var("VSS_KEYSTORE_PASSPHRASE") {
+        Ok(passphrase) => Ok(passphrase),
+        Err(_) => rpassword::prompt_password("Keystore passphrase: "),
+    }
+}
+
+/// Asks twice for the passphrase of a new keystore, unless it comes from
+/// VSS_KEYSTORE_PASSPHRASE, and fails if the two do not match.
+pub fn new_passphrase() -> io::Result<String> {
+    if let Ok(passphrase) = std::env::var("VSS_KEYSTORE_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+    let passphrase = rpassword::prompt_password("New keystore passphrase: ")?;
+    let confirmation = rpassword::prompt_password("Repeat passphrase: ")?;
+    if passphrase != confirmation {
+        return Err(invalid("passphrases do not match"));
+    }
+    Ok(passphrase)
+}
+
+fn format_entry(entry: &Entry) -> String {
+    let commitments: Vec<String> = entry.commitments.iter().map(|c| c.to_string()).collect();
+    let (p, q, g) = &entry.params;
+    format!(
+        "{};{},{};{};{},{},{}",
+        entry.name, entry.share.0, entry.share.1, commitments.join(","), p, q, g
+    )
+}
+
+fn parse_entry(line: &str) -> io::Result<Entry> {
+    let fields: Vec<&str> = line.split(';').collect();
+    if fields.len() != 4 {
+        return Err(invalid("malformed keystore entry"));
+    }
+
+    let share = parse_numbers(fields[1])?;
+    let commitments = if fields[2].is_empty() { Vec::new() } else { parse_numbers(fields[2])? };
+    let params = parse_numbers(fields[3])?;
+    if share.len() != 2 || params.len() != 3 {
+        return Err(invalid("malformed keystore entry"));
+    }
+
+    Ok(Entry {
+        name: fields[0].to_string(),
+        share: (share[0].clone(), share[1].clone()),
+        commitments,
+        params: (params[0].clone(), params[1].clone(), params[2].clone()),
+    })
+}
+
+fn parse_numbers(list: &str) -> io::Result<Vec<BigUint>> {
+    list.split(',')
+        .map(|s| BigUint::parse_bytes(s.as_bytes(), 10).ok_or_else(|| invalid("malformed number")))
+        .collect()
+}
+
+fn read_field(line: Option<&str>, prefix: &str) -> io::Result<Vec<u8>> {
+    line.and_then(|line| line.strip_prefix(prefix))
+        .and_then(|value| hex::decode(value).ok())
+        .ok_or_else(|| invalid("malformed keystore file"))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::params;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("vss-test-{}-{}.keystore", name, std::process::id()))
+    }
+
+    fn entry(name: &str) -> Entry {
+        Entry {
+            name: name.to_string(),
+            share: (BigUint::from(1u32), BigUint::from(12345u32)),
+            commitments: vec![BigUint::from(6u32), BigUint::from(7u32)],
+            params: params::get_fixed_params(),
+        }
+    }
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let path = temp_path("roundtrip");
+
+        let mut keystore = Keystore::create(&path, "correct horse").unwrap();
+        keystore.insert(entry("alice")).unwrap();
+        keystore.insert(entry("bob")).unwrap();
+        assert!(keystore.insert(entry("bob")).is_err());
+        keystore.save(&path, "correct horse").unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(!text.contains("12345"));
+
+        let mut loaded = Keystore::load(&path, "correct horse").unwrap();
+        assert_eq!(loaded.entries, vec![entry("alice"), entry("bob")]);
+        assert_eq!(loaded.remove("alice"), Some(entry("alice")));
+        assert!(loaded.get("alice").is_none());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_keystore_wrong_passphrase() {
+        let path = temp_path("wrong-passphrase");
+
+        let mut keystore = Keystore::default();
+        keystore.insert(entry("alice")).unwrap();
+        keystore.save(&path, "correct horse").unwrap();
+
+        let error = Keystore::load(&path, "battery staple").err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_keystore_must_be_created_first() {
+        let path = temp_path("missing");
+
+        let error = Keystore::load(&path, "correct horse").err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+
+        Keystore::create(&path, "correct horse").unwrap();
+        assert!(Keystore::load(&path, "correct horse").unwrap().entries.is_empty());
+        let error = Keystore::create(&path, "correct horse").err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_keystore_named_tmp_is_saved_in_place() {
+        let path = std::env::temp_dir().join(format!("vss-test-{}.tmp", std::process::id()));
+
+        let mut keystore = Keystore::create(&path, "correct horse").unwrap();
+        keystore.insert(entry("alice")).unwrap();
+        keystore.save(&path, "correct horse").unwrap();
+
+        let loaded = Keystore::load(&path, "correct horse").unwrap();
+        assert_eq!(loaded.entries, vec![entry("alice")]);
+        let mut leftover = path.as_os_str().to_owned();
+        leftover.push(".tmp");
+        assert!(!PathBuf::from(leftover).exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
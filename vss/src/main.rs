@@ -2,11 +2,15 @@ mod cli;
 mod crypto;
 mod math;
 mod commands;
+mod broadcast;
+mod net;
+mod keystore;
+mod transcript;
 
 use clap::Parser;
 use cli::Args;
 
 fn main() {
     let args = Args::parse();
-    commands::execute(args.cmd);
+    commands::execute(args.cmd, args.format);
 }
\ No newline at end of file
@@ -1,18 +1,73 @@
+use std::collections::HashSet;
+use std::fmt;
 use num_bigint::{BigInt, BigUint};
 use num_integer::Integer;
 use num_traits::{One, Zero};
 use super::gcd::extended_gcd;
 
-pub fn interpolate(shares: &[(BigUint, BigUint)], q: &BigUint) -> BigUint {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InterpolationError {
+    NoShares,
+    /// x = 0 would be the secret itself, never a share.
+    ZeroX(BigUint),
+    DuplicateX(BigUint),
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationError::NoShares => write!(f, "no shares given"),
+            InterpolationError::ZeroX(x) => write!(f, "share x={} is zero modulo q", x),
+            InterpolationError::DuplicateX(x) => write!(f, "share x={} appears more than once modulo q", x),
+        }
+    }
+}
+
+/// One share's contribution y_i * λ_i(0) to the secret.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermTrace {
+    pub x: BigUint,
+    pub y: BigUint,
+    pub numerator: BigUint,
+    pub denominator: BigUint,
+    pub denominator_inv: BigUint,
+    pub lagrange_coeff: BigUint,
+    pub term: BigUint,
+    pub running_secret: BigUint,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InterpolationTrace {
+    pub terms: Vec<TermTrace>,
+}
+
+/// Recovers f(0) from the shares. Silent; see `interpolate_traced` for the steps.
+pub fn interpolate(shares: &[(BigUint, BigUint)], q: &BigUint) -> Result<BigUint, InterpolationError> {
+    interpolate_inner(shares, q, None)
+}
+
+/// Same as `interpolate`, also returning every intermediate value.
+pub fn interpolate_traced(
+    shares: &[(BigUint, BigUint)],
+    q: &BigUint,
+) -> Result<(BigUint, InterpolationTrace), InterpolationError> {
+    let mut trace = InterpolationTrace::default();
+    let secret = interpolate_inner(shares, q, Some(&mut trace))?;
+    Ok((secret, trace))
+}
+
+fn interpolate_inner(
+    shares: &[(BigUint, BigUint)],
+    q: &BigUint,
+    mut trace: Option<&mut InterpolationTrace>,
+) -> Result<BigUint, InterpolationError> {
+    check_x_coordinates(shares, q)?;
+
     let mut secret = BigInt::zero();
     let q_int = BigInt::from(q.clone());
     
-    println!("Starting Lagrange interpolation...");
-    
     // For each share, compute its contribution to the secret
     for (i, (xi, yi)) in shares.iter().enumerate() {
-        println!("\nProcessing share {}: x={}, y={}", i + 1, xi, yi);
-        
         let mut numerator = BigInt::one();
         let mut denominator = BigInt::one();
         
@@ -29,34 +84,235 @@ pub fn interpolate(shares: &[(BigUint, BigUint)], q: &BigUint) -> BigUint {
             }
         }
         
-        println!("  numerator = {}", numerator);
-        println!("  denominator = {}", denominator);
-        
-        // Find the modular inverse of the denominator
-        let (gcd, inverse, _) = extended_gcd(denominator.clone(), q_int.clone());
-        println!("  gcd = {}", gcd);
-        
-        if gcd != BigInt::one() {
-            eprintln!("  ERROR: gcd is not 1, cannot compute modular inverse!");
-            continue;
-        }
-        
-        let denominator_inv = inverse.mod_floor(&q_int);
-        println!("  denominator_inv = {}", denominator_inv);
+        // Distinct non-zero x-coordinates guarantee the inverse exists (q is prime)
+        let denominator_inv = mod_inverse(&denominator, &q_int)
+            .ok_or_else(|| InterpolationError::DuplicateX(xi.clone()))?;
         
         // Compute the Lagrange coefficient
-        let lagrange_coeff = (numerator * denominator_inv).mod_floor(&q_int);
-        println!("  lagrange_coeff = {}", lagrange_coeff);
+        let lagrange_coeff = (&numerator * &denominator_inv).mod_floor(&q_int);
         
         // Add this share's contribution: yi * lagrange_coeff
-        let term = (BigInt::from(yi.clone()) * lagrange_coeff).mod_floor(&q_int);
-        println!("  term = {}", term);
-        
-        secret = (secret + term).mod_floor(&q_int);
-        println!("  running secret = {}", secret);
+        let term = (BigInt::from(yi.clone()) * &lagrange_coeff).mod_floor(&q_int);
+        secret = (secret + &term).mod_floor(&q_int);
+
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.terms.push(TermTrace {
+                x: xi.clone(),
+                y: yi.clone(),
+                numerator: to_biguint(numerator),
+                denominator: to_biguint(denominator),
+                denominator_inv: to_biguint(denominator_inv),
+                lagrange_coeff: to_biguint(lagrange_coeff),
+                term: to_biguint(term),
+                running_secret: to_biguint(secret.clone()),
+            });
+        }
     }
     
-    println!("\nFinal secret: {}", secret);
-    
-    secret.to_biguint().expect("Secret should be positive")
-}
\ No newline at end of file
+    Ok(to_biguint(secret))
+}
+
+fn check_x_coordinates(shares: &[(BigUint, BigUint)], q: &BigUint) -> Result<(), InterpolationError> {
+    if shares.is_empty() {
+        return Err(InterpolationError::NoShares);
+    }
+
+    let mut seen = HashSet::new();
+    for (x, _) in shares {
+        let reduced = x % q;
+        if reduced.is_zero() {
+            return Err(InterpolationError::ZeroX(x.clone()));
+        }
+        if !seen.insert(reduced) {
+            return Err(InterpolationError::DuplicateX(x.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Values reduced with mod_floor are never negative.
+fn to_biguint(value: BigInt) -> BigUint {
+    value.to_biguint().expect("value reduced mod q is non-negative")
+}
+
+/// Lagrange basis values λ_i(x) = ∏_{j≠i} (x - x_j) / (x_i - x_j) mod q, one per x-coordinate.
+/// Returns `None` if a denominator is not invertible (repeated x-coordinates).
+pub fn coefficients_at(xs: &[BigUint], x: &BigUint, q: &BigUint) -> Option<Vec<BigUint>> {
+    let q_int = BigInt::from(q.clone());
+    let x_int = BigInt::from(x.clone());
+
+    xs.iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            let mut numerator = BigInt::one();
+            let mut denominator = BigInt::one();
+
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    let xj_int = BigInt::from(xj.clone());
+                    numerator = (numerator * (&x_int - &xj_int)).mod_floor(&q_int);
+                    let difference = (BigInt::from(xi.clone()) - xj_int).mod_floor(&q_int);
+                    denominator = (denominator * difference).mod_floor(&q_int);
+                }
+            }
+
+            let inverse = mod_inverse(&denominator, &q_int)?;
+            (numerator * inverse).mod_floor(&q_int).to_biguint()
+        })
+        .collect()
+}
+
+/// Monomial coefficients of every Lagrange basis polynomial L_i(X), lowest degree first.
+pub fn basis_polynomials(xs: &[BigUint], q: &BigUint) -> Option<Vec<Vec<BigUint>>> {
+    let q_int = BigInt::from(q.clone());
+
+    xs.iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            // Start from the constant polynomial 1 and multiply in each (X - x_j).
+            let mut poly = vec![BigInt::one()];
+            let mut denominator = BigInt::one();
+
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    let xj_int = BigInt::from(xj.clone());
+                    let mut next = vec![BigInt::zero(); poly.len() + 1];
+                    for (d, c) in poly.iter().enumerate() {
+                        next[d + 1] = (&next[d + 1] + c).mod_floor(&q_int);
+                        next[d] = (&next[d] - c * &xj_int).mod_floor(&q_int);
+                    }
+                    poly = next;
+
+                    let difference = (BigInt::from(xi.clone()) - xj_int).mod_floor(&q_int);
+                    denominator = (denominator * difference).mod_floor(&q_int);
+                }
+            }
+
+            let inverse = mod_inverse(&denominator, &q_int)?;
+            poly.into_iter()
+                .map(|c| (c * &inverse).mod_floor(&q_int).to_biguint())
+                .collect()
+        })
+        .collect()
+}
+
+/// Lagrange interpolation in the exponent: from points (x_i, g^f(x_i)) recover
+/// g^f(x) = ∏ (g^f(x_i))^λ_i(x) mod p without ever learning f.
+pub fn interpolate_in_exponent(
+    points: &[(BigUint, BigUint)],
+    x: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<BigUint> {
+    let xs: Vec<BigUint> = points.iter().map(|(xi, _)| xi.clone()).collect();
+    let coefficients = coefficients_at(&xs, x, q)?;
+
+    let mut result = BigUint::one();
+    for ((_, yi), lambda) in points.iter().zip(coefficients.iter()) {
+        result = (result * yi.modpow(lambda, p)) % p;
+    }
+    Some(result)
+}
+
+/// Recovers the Feldman commitments C[j] = g^a_j from k points (x_i, g^f(x_i)),
+/// using C[j] = ∏ (g^f(x_i))^(L_i[j]) where L_i[j] is the j-th coefficient of L_i(X).
+pub fn coefficients_in_exponent(
+    points: &[(BigUint, BigUint)],
+    p: &BigUint,
+    q: &BigUint,
+) -> Option<Vec<BigUint>> {
+    let xs: Vec<BigUint> = points.iter().map(|(xi, _)| xi.clone()).collect();
+    let basis = basis_polynomials(&xs, q)?;
+
+    let commitments = (0..points.len())
+        .map(|j| {
+            let mut result = BigUint::one();
+            for ((_, yi), poly) in points.iter().zip(basis.iter()) {
+                result = (result * yi.modpow(&poly[j], p)) % p;
+            }
+            result
+        })
+        .collect();
+    Some(commitments)
+}
+
+fn mod_inverse(a: &BigInt, q: &BigInt) -> Option<BigInt> {
+    let (gcd, inverse, _) = extended_gcd(a.clone(), q.clone());
+    if gcd != BigInt::one() {
+        return None;
+    }
+    Some(inverse.mod_floor(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{feldman, polynomial};
+
+    #[test]
+    fn test_interpolate_in_exponent() {
+        let p = BigUint::from(23u32);
+        let q = BigUint::from(11u32);
+        let g = BigUint::from(2u32);
+
+        // Polynomial: f(x) = 5 + 3x + 7x^2
+        let coeffs = vec![BigUint::from(5u32), BigUint::from(3u32), BigUint::from(7u32)];
+        let commitments = feldman::generate_commitments(&coeffs, &g, &p);
+
+        let points: Vec<(BigUint, BigUint)> = [2u32, 5, 9]
+            .iter()
+            .map(|&x| {
+                let x = BigUint::from(x);
+                let key = feldman::verification_key(&commitments, &x, &p, &q);
+                (x, key)
+            })
+            .collect();
+
+        for x in 0u32..11 {
+            let x = BigUint::from(x);
+            let expected = g.modpow(&polynomial::evaluate(&coeffs, &x, &q), &p);
+            assert_eq!(interpolate_in_exponent(&points, &x, &p, &q), Some(expected));
+        }
+        assert_eq!(coefficients_in_exponent(&points, &p, &q), Some(commitments));
+    }
+
+    #[test]
+    fn test_interpolate_rejects_bad_x_coordinates() {
+        let q = BigUint::from(11u32);
+        let share = |x: u32, y: u32| (BigUint::from(x), BigUint::from(y));
+
+        // f(x) = 5 + 3x
+        assert_eq!(interpolate(&[share(1, 8), share(3, 3)], &q), Ok(BigUint::from(5u32)));
+
+        assert_eq!(interpolate(&[], &q), Err(InterpolationError::NoShares));
+        assert_eq!(
+            interpolate(&[share(0, 5), share(1, 8)], &q),
+            Err(InterpolationError::ZeroX(BigUint::from(0u32)))
+        );
+        assert_eq!(
+            interpolate(&[share(1, 8), share(12, 8)], &q),
+            Err(InterpolationError::DuplicateX(BigUint::from(12u32)))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_traced_matches_silent() {
+        let q = BigUint::from(11u32);
+        let shares = vec![
+            (BigUint::from(1u32), BigUint::from(8u32)),
+            (BigUint::from(3u32), BigUint::from(3u32)),
+        ];
+
+        let (secret, trace) = interpolate_traced(&shares, &q).unwrap();
+        assert_eq!(Ok(secret.clone()), interpolate(&shares, &q));
+        assert_eq!(trace.terms.len(), 2);
+        assert_eq!(trace.terms[1].running_secret, secret);
+    }
+
+    #[test]
+    fn test_repeated_x_is_rejected() {
+        let q = BigUint::from(11u32);
+        let xs = vec![BigUint::from(3u32), BigUint::from(14u32)];
+        assert!(coefficients_at(&xs, &BigUint::zero(), &q).is_none());
+    }
+}
@@ -0,0 +1,182 @@
+use std::io::{self, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+use num_bigint::BigUint;
+use super::protocol;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An authenticated connection to one shareholder daemon.
+pub struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    pub fn connect(address: &str, token: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        let mut connection = Connection {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        };
+
+        let hello = connection.read_line()?;
+        let nonce = hello
+            .strip_prefix("HELLO ")
+            .and_then(|nonce| hex::decode(nonce).ok())
+            .ok_or_else(|| protocol::protocol_error(format!("unexpected greeting '{}'", hello)))?;
+
+        let tag = protocol::auth_tag(token, &nonce);
+        connection.request(&format!("AUTH {}", hex::encode(tag)))?;
+
+        Ok(connection)
+    }
+
+    /// Asks the holder to check its own share against the commitments.
+    pub fn verify(&mut self, commitments: &[BigUint]) -> io::Result<bool> {
+        let reply = self.request(&format!("VERIFY {}", protocol::format_numbers(commitments)))?;
+        match reply.as_str() {
+            "VALID" => Ok(true),
+            "INVALID" => Ok(false),
+            _ => Err(protocol::protocol_error(format!("unexpected reply '{}'", reply))),
+        }
+    }
+
+    /// The holder's x-coordinate, which is public.
+    pub fn index(&mut self) -> io::Result<BigUint> {
+        let reply = self.request("INDEX")?;
+        let values = protocol::parse_numbers(&reply)?;
+        if values.len() != 1 {
+            return Err(protocol::protocol_error(format!("unexpected index '{}'", reply)));
+        }
+        Ok(values[0].clone())
+    }
+
+    /// The holder's masked share for the set `xs` under `nonce`; interpolating
+    /// the masked shares of the whole set at 0 gives the secret.
+    pub fn masked_share(&mut self, nonce: &[u8], xs: &[BigUint]) -> io::Result<BigUint> {
+        let request = format!("MASKED {} {}", hex::encode(nonce), protocol::format_numbers(xs));
+        let reply = self.request(&request)?;
+        let values = protocol::parse_numbers(&reply)?;
+        if values.len() != 2 {
+            return Err(protocol::protocol_error(format!("unexpected masked share '{}'", reply)));
+        }
+        Ok(values[1].clone())
+    }
+
+    pub fn quit(mut self) -> io::Result<()> {
+        writeln!(self.writer, "QUIT")
+    }
+
+    /// Sends one line and returns the payload after "OK".
+    fn request(&mut self, line: &str) -> io::Result<String> {
+        writeln!(self.writer, "{}", line)?;
+        let reply = self.read_line()?;
+
+        if reply == "OK" {
+            return Ok(String::new());
+        }
+        match reply.strip_prefix("OK ") {
+            Some(payload) => Ok(payload.to_string()),
+            None => Err(protocol::protocol_error(format!("server replied '{}'", reply))),
+        }
+    }
+
+    fn read_line(&mut self) -> io::Result<String> {
+        let mut line = String::new();
+        if protocol::read_line(&mut self.reader, &mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed"));
+        }
+        Ok(line.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+    use crate::crypto::{feldman, params, polynomial};
+    use crate::math::lagrange;
+    use crate::net::server::{self, Holder};
+
+    fn spawn_holder(x: u64, coefficients: &[BigUint], token: &str) -> String {
+        let (p, q, g) = params::get_fixed_params();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        let x = BigUint::from(x);
+        let y = polynomial::evaluate(coefficients, &x, &q);
+        let commitments = feldman::generate_commitments(coefficients, &g, &p);
+        let holder = Holder { x, y, commitments, token: token.to_string() };
+        thread::spawn(move || server::serve(listener, holder));
+
+        address
+    }
+
+    #[test]
+    fn test_coordinator_reconstructs_from_daemons() {
+        let (p, q, g) = params::get_fixed_params();
+        let coefficients = vec![BigUint::from(4242u32), BigUint::from(7u32), BigUint::from(99u32)];
+        let commitments = feldman::generate_commitments(&coefficients, &g, &p);
+
+        let addresses: Vec<String> = (1..=4)
+            .map(|x| spawn_holder(x, &coefficients, "token"))
+            .collect();
+
+        let mut connections: Vec<Connection> = addresses
+            .iter()
+            .skip(1)
+            .map(|address| Connection::connect(address, "token").unwrap())
+            .collect();
+        let xs: Vec<BigUint> = connections.iter_mut().map(|c| c.index().unwrap()).collect();
+
+        let nonce = [7u8; protocol::NONCE_LEN];
+        let mut points = Vec::new();
+        for (connection, x) in connections.iter_mut().zip(xs.iter()) {
+            assert!(connection.verify(&commitments).unwrap());
+            let masked = connection.masked_share(&nonce, &xs).unwrap();
+            // The answer is not the share itself.
+            assert_ne!(masked, polynomial::evaluate(&coefficients, x, &q));
+            points.push((x.clone(), masked));
+        }
+        assert_eq!(lagrange::interpolate(&points, &q), Ok(coefficients[0].clone()));
+
+        // A set without the holder's own index, or smaller than k, is refused.
+        assert!(connections[0].masked_share(&nonce, &[xs[1].clone(), xs[2].clone(), BigUint::from(9u32)]).is_err());
+        assert!(connections[0].masked_share(&nonce, &xs[..2]).is_err());
+        for connection in connections {
+            connection.quit().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_overlong_line_ends_the_connection() {
+        use std::io::Read;
+
+        let coefficients = vec![BigUint::from(4242u32), BigUint::from(7u32)];
+        let address = spawn_holder(1, &coefficients, "token");
+
+        let mut stream = TcpStream::connect(&address).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut hello = String::new();
+        protocol::read_line(&mut reader, &mut hello).unwrap();
+
+        // No newline at all, and more than the daemon accepts before AUTH.
+        let _ = stream.write_all(&vec![b'a'; protocol::MAX_LINE + 16]);
+        let mut rest = Vec::new();
+        let _ = reader.read_to_end(&mut rest);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_wrong_token_is_rejected() {
+        let coefficients = vec![BigUint::from(4242u32), BigUint::from(7u32)];
+        let address = spawn_holder(1, &coefficients, "token");
+
+        assert!(Connection::connect(&address, "not the token").is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! Line-based TCP protocol between a shareholder daemon (`vss serve`) and a
+//! coordinator (`vss coordinate`). Every message is one UTF-8 line ending in '\n'.
+//!
+//! ```text
+//! server: HELLO <nonce-hex>                     16 random bytes
+//! client: AUTH <hmac-hex>                       HMAC-SHA256(token, nonce)
+//! server: OK | ERR unauthorized                 connection closes on ERR
+//!
+//! client: VERIFY <c0,c1,...>                    check the stored share
+//! server: OK VALID | OK INVALID
+//!
+//! client: INDEX                                 the holder's public x
+//! server: OK <x>
+//!
+//! client: MASKED <nonce-hex> <x1,x2,...>         16 fresh random bytes, at least k indices
+//! server: OK <x>,<y + m/λ_i mod q>              masked share for this nonce and set
+//!
+//! client: QUIT
+//! ```
+//!
+//! λ_i is the holder's Lagrange weight at 0 for the set, and m is its share of a
+//! zero-sharing keyed by g^(y_i y_j) for every other member j (see
+//! `crypto::masking`). Interpolating the masked shares of the whole set at 0
+//! gives the secret, while one answer, or answers for different nonces or
+//! sets, say nothing about y. The daemon pins the commitments at startup, so a
+//! coordinator cannot pick the keys the masks come from.
+//!
+//! Any malformed request is answered with `ERR <reason>`. Lines longer than
+//! 64 KiB end the connection, and a daemon already serving its limit of
+//! connections answers `ERR busy` and hangs up.
+
+pub mod protocol;
+pub mod server;
+pub mod client;
\ No newline at end of file
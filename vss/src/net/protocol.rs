@@ -0,0 +1,55 @@
+use std::io::{self, BufRead, Read};
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const NONCE_LEN: usize = 16;
+/// Longest line either side accepts, newline included.
+pub const MAX_LINE: usize = 64 * 1024;
+
+/// Reads one line of at most MAX_LINE bytes into `line`, so a peer that never
+/// sends a newline cannot grow it without bound. Returns 0 at end of stream.
+pub fn read_line(reader: &mut impl BufRead, line: &mut String) -> io::Result<usize> {
+    line.clear();
+    let read = reader.by_ref().take(MAX_LINE as u64 + 1).read_line(line)?;
+    if read > MAX_LINE {
+        return Err(protocol_error(format!("line longer than {} bytes", MAX_LINE)));
+    }
+    Ok(read)
+}
+
+pub fn auth_tag(token: &str, nonce: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(token.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(nonce);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Constant-time check of the coordinator's answer to the challenge.
+pub fn check_auth_tag(token: &str, nonce: &[u8], tag: &[u8]) -> bool {
+    let mut mac = HmacSha256::new_from_slice(token.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(nonce);
+    mac.verify_slice(tag).is_ok()
+}
+
+pub fn format_numbers(values: &[BigUint]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub fn parse_numbers(list: &str) -> io::Result<Vec<BigUint>> {
+    list.split(',')
+        .map(|s| {
+            BigUint::parse_bytes(s.trim().as_bytes(), 10)
+                .ok_or_else(|| protocol_error(format!("invalid number '{}'", s.trim())))
+        })
+        .collect()
+}
+
+pub fn protocol_error(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -0,0 +1,149 @@
+use std::io::{self, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use rand::RngCore;
+use crate::crypto::{params, feldman, masking};
+use crate::math::lagrange;
+use super::protocol;
+
+/// Connections handled at once; further coordinators get "ERR busy".
+const MAX_CONNECTIONS: usize = 32;
+/// An idle or stalled peer is dropped after this long.
+const TIMEOUT: Duration = Duration::from_secs(30);
+/// Largest x set a MASKED request may name.
+const MAX_PARTIAL_SET: usize = 1024;
+
+pub struct Holder {
+    pub x: BigUint,
+    pub y: BigUint,
+    /// Pinned at startup; the pair keys of the masks come from these.
+    pub commitments: Vec<BigUint>,
+    pub token: String,
+}
+
+/// Accepts connections forever, one thread per coordinator, at most
+/// MAX_CONNECTIONS at a time. A failed accept is logged and skipped.
+pub fn serve(listener: TcpListener, holder: Holder) -> io::Result<()> {
+    let holder = Arc::new(holder);
+    let active = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Accept failed: {}", e);
+                continue;
+            }
+        };
+        if active.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            active.fetch_sub(1, Ordering::SeqCst);
+            let _ = writeln!(stream, "ERR busy");
+            continue;
+        }
+
+        let holder = Arc::clone(&holder);
+        let active = Arc::clone(&active);
+        thread::spawn(move || {
+            let peer = stream.peer_addr().map(|a| a.to_string()).unwrap_or_default();
+            if let Err(e) = handle_connection(stream, &holder) {
+                eprintln!("Connection {}: {}", peer, e);
+            }
+            active.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    Ok(())
+}
+
+/// This holder's masked share y_i + m_i / λ_i for the coordinator's set `xs`,
+/// which must contain the holder's own x and at least k indices. Interpolating
+/// the masked shares of the whole set at 0 gives the secret, since the masks m_i
+/// add up to zero; a single answer says nothing about y_i.
+fn masked_share(holder: &Holder, nonce: &[u8], xs: &[BigUint], p: &BigUint, q: &BigUint) -> Result<BigUint, String> {
+    if nonce.len() != protocol::NONCE_LEN {
+        return Err(format!("the nonce must be {} bytes", protocol::NONCE_LEN));
+    }
+    if xs.len() < holder.commitments.len() || xs.len() > MAX_PARTIAL_SET {
+        return Err(format!("the set must name {} to {} indices", holder.commitments.len(), MAX_PARTIAL_SET));
+    }
+    let position = xs
+        .iter()
+        .position(|x| x == &holder.x)
+        .ok_or("the set does not contain this holder's index")?;
+    let weights = lagrange::coefficients_at(xs, &BigUint::zero(), q)
+        .ok_or("indices must be distinct")?;
+    if weights[position].is_zero() {
+        return Err("indices must not be zero".to_string());
+    }
+
+    let mask = masking::mask(&holder.x, &holder.y, xs, nonce, &holder.commitments, p, q);
+    let weight_inv = weights[position].modpow(&(q - 2u32), q);
+    Ok((&holder.y + mask * weight_inv) % q)
+}
+
+fn handle_connection(stream: TcpStream, holder: &Holder) -> io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    let mut nonce = [0u8; protocol::NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    writeln!(writer, "HELLO {}", hex::encode(nonce))?;
+
+    let mut line = String::new();
+    protocol::read_line(&mut reader, &mut line)?;
+    let authorized = line
+        .trim()
+        .strip_prefix("AUTH ")
+        .and_then(|tag| hex::decode(tag).ok())
+        .is_some_and(|tag| protocol::check_auth_tag(&holder.token, &nonce, &tag));
+    if !authorized {
+        writeln!(writer, "ERR unauthorized")?;
+        return Ok(());
+    }
+    writeln!(writer, "OK")?;
+
+    let (p, q, g) = params::get_fixed_params();
+
+    loop {
+        if protocol::read_line(&mut reader, &mut line)? == 0 {
+            return Ok(());
+        }
+        let request = line.trim();
+        let (command, argument) = request.split_once(' ').unwrap_or((request, ""));
+
+        match command {
+            "VERIFY" => match protocol::parse_numbers(argument) {
+                Ok(commitments) => {
+                    if feldman::verify_share(&holder.x, &holder.y, &commitments, &g, &p, &q) {
+                        writeln!(writer, "OK VALID")?;
+                    } else {
+                        writeln!(writer, "OK INVALID")?;
+                    }
+                }
+                Err(e) => writeln!(writer, "ERR {}", e)?,
+            },
+            "INDEX" => writeln!(writer, "OK {}", holder.x)?,
+            "MASKED" => {
+                let (nonce, xs) = argument.split_once(' ').unwrap_or((argument, ""));
+                let reply = hex::decode(nonce)
+                    .map_err(|_| "the nonce must be hex".to_string())
+                    .and_then(|nonce| {
+                        let xs = protocol::parse_numbers(xs).map_err(|e| e.to_string())?;
+                        masked_share(holder, &nonce, &xs, &p, &q)
+                    });
+                match reply {
+                    Ok(value) => writeln!(writer, "OK {},{}", holder.x, value)?,
+                    Err(e) => writeln!(writer, "ERR {}", e)?,
+                }
+            }
+            "QUIT" => return Ok(()),
+            _ => writeln!(writer, "ERR unknown command '{}'", command)?,
+        }
+    }
+}
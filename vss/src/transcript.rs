@@ -0,0 +1,186 @@
+//! Public record of a dealing.
+//!
+//! Holds nothing secret: the group parameters, the commitments and the
+//! x-coordinate of every share issued so far, one line each:
+//! ```text
+//! vss-transcript v1
+//! params p,q,g
+//! commitments c0,c1,...
+//! holder 1
+//! holder 8235619420912 alice@example.com
+//! ```
+//! A holder line may end with the identifier its x was derived from, so a share
+//! can be named by identifier instead of x. `enroll` appends a line for every
+//! new shareholder.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+
+const HEADER: &str = "vss-transcript v1";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Holder {
+    pub x: BigUint,
+    /// Identifier x was derived from with `id_to_x`, if any.
+    pub id: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transcript {
+    pub params: (BigUint, BigUint, BigUint),
+    pub commitments: Vec<BigUint>,
+    pub holders: Vec<Holder>,
+}
+
+/// Stable x-coordinate for an identifier such as an employee ID or a public key,
+/// independent of the order holders are listed in. Zero is possible in theory and
+/// is rejected by `enroll` like any other zero x.
+pub fn id_to_x(id: &str, q: &BigUint) -> BigUint {
+    let mut hasher = Sha256::new();
+    hasher.update(b"vss-holder-id");
+    hasher.update(id.as_bytes());
+    BigUint::from_bytes_be(&hasher.finalize()) % q
+}
+
+impl Transcript {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("not a vss transcript"));
+        }
+
+        let params = parse_numbers(lines.next(), "params ")?;
+        let commitments = parse_numbers(lines.next(), "commitments ")?;
+        if params.len() != 3 || commitments.is_empty() {
+            return Err(invalid("malformed transcript"));
+        }
+
+        let holders = lines
+            .filter(|line| !line.is_empty())
+            .map(parse_holder)
+            .collect::<io::Result<Vec<Holder>>>()?;
+
+        Ok(Transcript {
+            params: (params[0].clone(), params[1].clone(), params[2].clone()),
+            commitments,
+            holders,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let (p, q, g) = &self.params;
+        let commitments: Vec<String> = self.commitments.iter().map(|c| c.to_string()).collect();
+
+        let mut text = format!("{}\nparams {},{},{}\ncommitments {}\n", HEADER, p, q, g, commitments.join(","));
+        for holder in &self.holders {
+            match &holder.id {
+                Some(id) => text.push_str(&format!("holder {} {}\n", holder.x, id)),
+                None => text.push_str(&format!("holder {}\n", holder.x)),
+            }
+        }
+
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Records a new shareholder. x must be non-zero and not issued yet, and an
+    /// identifier must be new, non-empty and fit on one line.
+    pub fn enroll(&mut self, holder: Holder) -> io::Result<()> {
+        let q = &self.params.1;
+        if (&holder.x % q).is_zero() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "x must be non-zero modulo q"));
+        }
+        if let Some(id) = &holder.id {
+            if id.trim().is_empty() || id.trim() != id || id.contains(['\n', '\r']) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "identifiers must be non-empty, one line, without surrounding spaces",
+                ));
+            }
+            if self.find(id).is_some() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("identifier '{}' is already enrolled", id),
+                ));
+            }
+        }
+        if self.holders.iter().any(|existing| &existing.x % q == &holder.x % q) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("a share at x={} was already issued", holder.x),
+            ));
+        }
+        self.holders.push(holder);
+        Ok(())
+    }
+
+    pub fn find(&self, id: &str) -> Option<&Holder> {
+        self.holders.iter().find(|holder| holder.id.as_deref() == Some(id))
+    }
+
+    pub fn is_issued(&self, x: &BigUint) -> bool {
+        let q = &self.params.1;
+        self.holders.iter().any(|holder| &holder.x % q == x % q)
+    }
+}
+
+fn parse_holder(line: &str) -> io::Result<Holder> {
+    let rest = line.strip_prefix("holder ").ok_or_else(|| invalid("malformed holder line"))?;
+    let (x, id) = match rest.split_once(' ') {
+        Some((x, id)) => (x, Some(id.to_string())),
+        None => (rest, None),
+    };
+    let x = BigUint::parse_bytes(x.as_bytes(), 10).ok_or_else(|| invalid("malformed number"))?;
+    Ok(Holder { x, id })
+}
+
+fn parse_numbers(line: Option<&str>, prefix: &str) -> io::Result<Vec<BigUint>> {
+    let list = line
+        .and_then(|line| line.strip_prefix(prefix))
+        .ok_or_else(|| invalid("malformed transcript"))?;
+    list.split(',')
+        .map(|s| BigUint::parse_bytes(s.as_bytes(), 10).ok_or_else(|| invalid("malformed number")))
+        .collect()
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::params;
+
+    #[test]
+    fn test_transcript_roundtrip_and_enroll() {
+        let path = std::env::temp_dir().join(format!("vss-test-{}.transcript", std::process::id()));
+
+        let (_p, q, _g) = params::get_fixed_params();
+        let holder = |x: BigUint, id: Option<&str>| Holder { x, id: id.map(str::to_string) };
+
+        let mut transcript = Transcript {
+            params: params::get_fixed_params(),
+            commitments: vec![BigUint::from(6u32), BigUint::from(7u32)],
+            holders: (1u32..=3).map(|x| holder(BigUint::from(x), None)).collect(),
+        };
+        assert!(transcript.enroll(holder(BigUint::from(2u32), None)).is_err());
+        assert!(transcript.enroll(holder(BigUint::zero(), None)).is_err());
+        transcript.enroll(holder(BigUint::from(9u32), None)).unwrap();
+
+        let alice = id_to_x("alice smith", &q);
+        transcript.enroll(holder(alice.clone(), Some("alice smith"))).unwrap();
+        assert!(transcript.enroll(holder(BigUint::from(10u32), Some("alice smith"))).is_err());
+        assert_eq!(transcript.find("alice smith").map(|h| &h.x), Some(&alice));
+
+        transcript.save(&path).unwrap();
+        assert_eq!(Transcript::load(&path).unwrap(), transcript);
+        fs::remove_file(&path).unwrap();
+    }
+}